@@ -0,0 +1,14 @@
+// 使用protox编译proto，无需安装protoc
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto");
+    let file_descriptors = protox::compile(["proto/location.proto"], ["proto"])?;
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+    // 用于grpc reflection
+    std::fs::write(
+        out_dir.join("location_descriptor.bin"),
+        prost::Message::encode_to_vec(&file_descriptors),
+    )?;
+    // client用于测试
+    tonic_prost_build::configure().compile_fds(file_descriptors)?;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use axum::http::{header, HeaderMap, HeaderValue};
+use std::sync::LazyLock;
+
+use crate::config;
+use crate::ip_data;
+
+// 查询结果的缓存设置，数据仅在数据集更新时变化
+static LOOKUP_CACHE_CONTROL: LazyLock<HeaderValue> = LazyLock::new(|| {
+    config::get_env("LOOKUP_CACHE_CONTROL")
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+        .unwrap_or_else(|| HeaderValue::from_static("public, max-age=3600"))
+});
+
+// 查询客户端自身ip或带api key的查询，结果与客户端相关，不可被共享缓存
+static PRIVATE_LOOKUP_CACHE_CONTROL: HeaderValue = HeaderValue::from_static("private, no-cache");
+
+// fnv-1a，需要保证相同数据在不同实例生成的值一致
+fn fnv1a(values: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for value in values {
+        for b in value.bytes().chain(std::iter::once(0)) {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+// 基于数据集版本与ip(以及响应格式等)生成etag
+pub fn new_lookup_etag(values: &[&str]) -> String {
+    format!(r#"W/"{:x}-{:x}""#, ip_data::GENERATED_AT, fnv1a(values))
+}
+
+pub fn lookup_cache_control(private: bool) -> HeaderValue {
+    if private {
+        PRIVATE_LOOKUP_CACHE_CONTROL.clone()
+    } else {
+        LOOKUP_CACHE_CONTROL.clone()
+    }
+}
+
+// 去除弱校验的前缀
+fn trim_weak(value: &str) -> &str {
+    value.trim().trim_start_matches("W/")
+}
+
+// 判断If-None-Match是否匹配etag，匹配则可返回304
+pub fn is_not_modified(headers: &HeaderMap, etag: &str) -> bool {
+    let Some(value) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    let etag = trim_weak(etag);
+    value
+        .split(',')
+        .any(|item| item.trim() == "*" || trim_weak(item) == etag)
+}
@@ -0,0 +1,102 @@
+use axum::http::{header, HeaderMap};
+use std::io::Write;
+use tower_http::compression::{
+    predicate::{DefaultPredicate, Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+use crate::config;
+
+// 小于此大小的数据不压缩
+pub fn min_size() -> usize {
+    config::get_env_number("COMPRESSION_MIN_SIZE", 1024_usize)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Br,
+    Zstd,
+    Gzip,
+}
+
+impl Encoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Br => "br",
+            Encoding::Zstd => "zstd",
+            Encoding::Gzip => "gzip",
+        }
+    }
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "br" => Some(Encoding::Br),
+            "zstd" => Some(Encoding::Zstd),
+            "gzip" => Some(Encoding::Gzip),
+            _ => None,
+        }
+    }
+}
+
+// 根据Accept-Encoding选择压缩方式，q值相同时按br, zstd, gzip的顺序
+pub fn negotiate(headers: &HeaderMap) -> Option<Encoding> {
+    let value = headers
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())?;
+    let mut result: Option<(Encoding, f32)> = None;
+    for item in value.split(',') {
+        let mut arr = item.split(';');
+        let Some(encoding) = Encoding::from_name(arr.next().unwrap_or_default().trim()) else {
+            continue;
+        };
+        let q = arr
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if q <= 0.0 {
+            continue;
+        }
+        let better = match result {
+            Some((current, current_q)) => {
+                q > current_q || (q == current_q && (encoding as u8) < (current as u8))
+            }
+            None => true,
+        };
+        if better {
+            result = Some((encoding, q));
+        }
+    }
+    result.map(|(encoding, _)| encoding)
+}
+
+// 文本类的数据才压缩
+pub fn is_compressible(content_type: &str) -> bool {
+    content_type.starts_with("text/")
+        || ["javascript", "json", "xml", "svg", "wasm"]
+            .iter()
+            .any(|value| content_type.contains(value))
+}
+
+pub fn compress(data: &[u8], encoding: Encoding) -> std::io::Result<Vec<u8>> {
+    match encoding {
+        Encoding::Br => {
+            let mut buf = vec![];
+            {
+                let mut writer = brotli::CompressorWriter::new(&mut buf, 4096, 11, 22);
+                writer.write_all(data)?;
+            }
+            Ok(buf)
+        }
+        Encoding::Zstd => zstd::encode_all(data, 19),
+        Encoding::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+    }
+}
+
+// api响应的压缩，大于COMPRESSION_MIN_SIZE才压缩
+pub fn new_compression_layer() -> CompressionLayer<impl Predicate> {
+    let min_size = min_size().min(u16::MAX as usize) as u16;
+    CompressionLayer::new().compress_when(DefaultPredicate::new().and(SizeAbove::new(min_size)))
+}
@@ -0,0 +1,38 @@
+use std::env;
+use std::str::FromStr;
+
+// 获取环境变量，空字符串视为未设置
+pub fn get_env(key: &str) -> Option<String> {
+    env::var(key)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+// 获取bool类型的环境变量，支持true/1/yes/on
+pub fn get_env_bool(key: &str, default: bool) -> bool {
+    match get_env(key) {
+        Some(value) => matches!(value.to_lowercase().as_str(), "true" | "1" | "yes" | "on"),
+        None => default,
+    }
+}
+
+// 获取数值类型的环境变量，解析失败则使用默认值
+pub fn get_env_number<T: FromStr>(key: &str, default: T) -> T {
+    get_env(key)
+        .and_then(|value| value.parse::<T>().ok())
+        .unwrap_or(default)
+}
+
+// 获取以,分隔的列表环境变量
+pub fn get_env_list(key: &str) -> Vec<String> {
+    get_env(key)
+        .map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
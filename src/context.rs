@@ -0,0 +1,16 @@
+// 请求相关的task local，由entry中间件设置
+// 独立于中间件，error等模块也可读取
+
+pub fn clone_value_from_task_local<T>(value: &T) -> T
+where
+    T: Clone,
+{
+    value.clone()
+}
+
+tokio::task_local! {
+    // 请求处理开始时间(毫秒)
+    pub static STARTED_AT: i64;
+    // 请求id
+    pub static REQUEST_ID: String;
+}
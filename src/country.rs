@@ -0,0 +1,94 @@
+use crate::country_data::{CountryInfo, COUNTRY_INFOS};
+use crate::ip::Location;
+
+// 国家(地区)的扩展信息
+pub fn get_country_info(code: &str) -> Option<&'static CountryInfo> {
+    COUNTRY_INFOS
+        .binary_search_by_key(&code, |(value, _)| value)
+        .ok()
+        .map(|index| &COUNTRY_INFOS[index].1)
+}
+
+// 根据国家代码设置大洲、区域、欧盟成员等信息
+pub fn enrich(location: &mut Location) {
+    let Some(info) = get_country_info(&location.country) else {
+        return;
+    };
+    location.continent = info.continent.to_string();
+    location.region = info.region.to_string();
+    location.subregion = info.subregion.to_string();
+    location.eu = info.eu;
+    location.eea = info.eea;
+    location.calling_code = info.calling_code.to_string();
+    location.currency = info.currency.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::country_data::{CONTINENT_NAMES, REGION_NAMES};
+
+    fn enrich_country(country: &str) -> Location {
+        let mut location = Location {
+            country: country.to_string(),
+            ..Default::default()
+        };
+        enrich(&mut location);
+        location
+    }
+
+    #[test]
+    fn sorted_country_infos() {
+        // 使用二分查找，因此需按国家代码排序
+        assert!(COUNTRY_INFOS.windows(2).all(|arr| arr[0].0 < arr[1].0));
+        for (code, info) in COUNTRY_INFOS.iter() {
+            assert!(std::ptr::eq(info, get_country_info(code).unwrap()));
+        }
+    }
+
+    #[test]
+    fn known_region_codes() {
+        let has_name = |names: &[(&str, [&str; 10])], code: &str| {
+            code.is_empty() || names.iter().any(|(value, _)| *value == code)
+        };
+        for (code, info) in COUNTRY_INFOS.iter() {
+            assert!(has_name(&CONTINENT_NAMES, info.continent), "{code}");
+            assert!(has_name(&REGION_NAMES, info.region), "{code}");
+            assert!(has_name(&REGION_NAMES, info.subregion), "{code}");
+        }
+    }
+
+    #[test]
+    fn enrich_location() {
+        let location = enrich_country("CN");
+        assert_eq!("AS", location.continent);
+        assert_eq!("142", location.region);
+        assert_eq!("030", location.subregion);
+        assert_eq!("+86", location.calling_code);
+        assert_eq!("CNY", location.currency);
+
+        // 欧盟与欧洲经济区
+        for (country, eu, eea) in [
+            ("DE", true, true),
+            ("RE", true, true),
+            ("NO", false, true),
+            ("CH", false, false),
+            ("GB", false, false),
+            ("CN", false, false),
+        ] {
+            let location = enrich_country(country);
+            assert_eq!(eu, location.eu, "{country}");
+            assert_eq!(eea, location.eea, "{country}");
+        }
+
+        // 未知的国家不设置
+        let location = enrich_country("ZZ");
+        assert_eq!(
+            Location {
+                country: "ZZ".to_string(),
+                ..Default::default()
+            },
+            location
+        );
+    }
+}
@@ -0,0 +1,7203 @@
+// 国家(地区)名称，数据来源于CLDR
+// 按国家代码排序，名称的顺序与COUNTRY_NAME_LANGS一致
+pub static COUNTRY_NAME_LANGS: [&str; 10] = [
+    "en", "zh-CN", "zh-TW", "ja", "ko", "de", "fr", "es", "ru", "pt",
+];
+
+pub static COUNTRY_NAMES: [(&str, [&str; 10]); 250] = [
+    (
+        "AD",
+        [
+            "Andorra",
+            "安道尔",
+            "安道爾",
+            "アンドラ",
+            "안도라",
+            "Andorra",
+            "Andorre",
+            "Andorra",
+            "Андорра",
+            "Andorra",
+        ],
+    ),
+    (
+        "AE",
+        [
+            "United Arab Emirates",
+            "阿拉伯联合酋长国",
+            "阿拉伯聯合大公國",
+            "アラブ首長国連邦",
+            "아랍에미리트",
+            "Vereinigte Arabische Emirate",
+            "Émirats arabes unis",
+            "Emiratos Árabes Unidos",
+            "ОАЭ",
+            "Emirados Árabes Unidos",
+        ],
+    ),
+    (
+        "AF",
+        [
+            "Afghanistan",
+            "阿富汗",
+            "阿富汗",
+            "アフガニスタン",
+            "아프가니스탄",
+            "Afghanistan",
+            "Afghanistan",
+            "Afganistán",
+            "Афганистан",
+            "Afeganistão",
+        ],
+    ),
+    (
+        "AG",
+        [
+            "Antigua & Barbuda",
+            "安提瓜和巴布达",
+            "安地卡及巴布達",
+            "アンティグア・バーブーダ",
+            "앤티가 바부다",
+            "Antigua und Barbuda",
+            "Antigua-et-Barbuda",
+            "Antigua y Barbuda",
+            "Антигуа и Барбуда",
+            "Antígua e Barbuda",
+        ],
+    ),
+    (
+        "AI",
+        [
+            "Anguilla",
+            "安圭拉",
+            "安奎拉",
+            "アンギラ",
+            "앵귈라",
+            "Anguilla",
+            "Anguilla",
+            "Anguila",
+            "Ангилья",
+            "Anguila",
+        ],
+    ),
+    (
+        "AL",
+        [
+            "Albania",
+            "阿尔巴尼亚",
+            "阿爾巴尼亞",
+            "アルバニア",
+            "알바니아",
+            "Albanien",
+            "Albanie",
+            "Albania",
+            "Албания",
+            "Albânia",
+        ],
+    ),
+    (
+        "AM",
+        [
+            "Armenia",
+            "亚美尼亚",
+            "亞美尼亞",
+            "アルメニア",
+            "아르메니아",
+            "Armenien",
+            "Arménie",
+            "Armenia",
+            "Армения",
+            "Armênia",
+        ],
+    ),
+    (
+        "AO",
+        [
+            "Angola",
+            "安哥拉",
+            "安哥拉",
+            "アンゴラ",
+            "앙골라",
+            "Angola",
+            "Angola",
+            "Angola",
+            "Ангола",
+            "Angola",
+        ],
+    ),
+    (
+        "AQ",
+        [
+            "Antarctica",
+            "南极洲",
+            "南極洲",
+            "南極",
+            "남극 대륙",
+            "Antarktis",
+            "Antarctique",
+            "Antártida",
+            "Антарктида",
+            "Antártida",
+        ],
+    ),
+    (
+        "AR",
+        [
+            "Argentina",
+            "阿根廷",
+            "阿根廷",
+            "アルゼンチン",
+            "아르헨티나",
+            "Argentinien",
+            "Argentine",
+            "Argentina",
+            "Аргентина",
+            "Argentina",
+        ],
+    ),
+    (
+        "AS",
+        [
+            "American Samoa",
+            "美属萨摩亚",
+            "美屬薩摩亞",
+            "米領サモア",
+            "아메리칸 사모아",
+            "Amerikanisch-Samoa",
+            "Samoa américaines",
+            "Samoa Americana",
+            "Американское Самоа",
+            "Samoa Americana",
+        ],
+    ),
+    (
+        "AT",
+        [
+            "Austria",
+            "奥地利",
+            "奧地利",
+            "オーストリア",
+            "오스트리아",
+            "Österreich",
+            "Autriche",
+            "Austria",
+            "Австрия",
+            "Áustria",
+        ],
+    ),
+    (
+        "AU",
+        [
+            "Australia",
+            "澳大利亚",
+            "澳洲",
+            "オーストラリア",
+            "오스트레일리아",
+            "Australien",
+            "Australie",
+            "Australia",
+            "Австралия",
+            "Austrália",
+        ],
+    ),
+    (
+        "AW",
+        [
+            "Aruba",
+            "阿鲁巴",
+            "荷屬阿魯巴",
+            "アルバ",
+            "아루바",
+            "Aruba",
+            "Aruba",
+            "Aruba",
+            "Аруба",
+            "Aruba",
+        ],
+    ),
+    (
+        "AX",
+        [
+            "Åland Islands",
+            "奥兰群岛",
+            "奧蘭群島",
+            "オーランド諸島",
+            "올란드 제도",
+            "Ålandinseln",
+            "Îles Åland",
+            "Islas Aland",
+            "Аландские о-ва",
+            "Ilhas Aland",
+        ],
+    ),
+    (
+        "AZ",
+        [
+            "Azerbaijan",
+            "阿塞拜疆",
+            "亞塞拜然",
+            "アゼルバイジャン",
+            "아제르바이잔",
+            "Aserbaidschan",
+            "Azerbaïdjan",
+            "Azerbaiyán",
+            "Азербайджан",
+            "Azerbaijão",
+        ],
+    ),
+    (
+        "BA",
+        [
+            "Bosnia & Herzegovina",
+            "波斯尼亚和黑塞哥维那",
+            "波士尼亞與赫塞哥維納",
+            "ボスニア・ヘルツェゴビナ",
+            "보스니아 헤르체고비나",
+            "Bosnien und Herzegowina",
+            "Bosnie-Herzégovine",
+            "Bosnia y Herzegovina",
+            "Босния и Герцеговина",
+            "Bósnia e Herzegovina",
+        ],
+    ),
+    (
+        "BB",
+        [
+            "Barbados",
+            "巴巴多斯",
+            "巴貝多",
+            "バルバドス",
+            "바베이도스",
+            "Barbados",
+            "Barbade",
+            "Barbados",
+            "Барбадос",
+            "Barbados",
+        ],
+    ),
+    (
+        "BD",
+        [
+            "Bangladesh",
+            "孟加拉国",
+            "孟加拉",
+            "バングラデシュ",
+            "방글라데시",
+            "Bangladesch",
+            "Bangladesh",
+            "Bangladés",
+            "Бангладеш",
+            "Bangladesh",
+        ],
+    ),
+    (
+        "BE",
+        [
+            "Belgium",
+            "比利时",
+            "比利時",
+            "ベルギー",
+            "벨기에",
+            "Belgien",
+            "Belgique",
+            "Bélgica",
+            "Бельгия",
+            "Bélgica",
+        ],
+    ),
+    (
+        "BF",
+        [
+            "Burkina Faso",
+            "布基纳法索",
+            "布吉納法索",
+            "ブルキナファソ",
+            "부르키나파소",
+            "Burkina Faso",
+            "Burkina Faso",
+            "Burkina Faso",
+            "Буркина-Фасо",
+            "Burquina Faso",
+        ],
+    ),
+    (
+        "BG",
+        [
+            "Bulgaria",
+            "保加利亚",
+            "保加利亞",
+            "ブルガリア",
+            "불가리아",
+            "Bulgarien",
+            "Bulgarie",
+            "Bulgaria",
+            "Болгария",
+            "Bulgária",
+        ],
+    ),
+    (
+        "BH",
+        [
+            "Bahrain",
+            "巴林",
+            "巴林",
+            "バーレーン",
+            "바레인",
+            "Bahrain",
+            "Bahreïn",
+            "Baréin",
+            "Бахрейн",
+            "Barein",
+        ],
+    ),
+    (
+        "BI",
+        [
+            "Burundi",
+            "布隆迪",
+            "蒲隆地",
+            "ブルンジ",
+            "부룬디",
+            "Burundi",
+            "Burundi",
+            "Burundi",
+            "Бурунди",
+            "Burundi",
+        ],
+    ),
+    (
+        "BJ",
+        [
+            "Benin",
+            "贝宁",
+            "貝南",
+            "ベナン",
+            "베냉",
+            "Benin",
+            "Bénin",
+            "Benín",
+            "Бенин",
+            "Benin",
+        ],
+    ),
+    (
+        "BL",
+        [
+            "St. Barthélemy",
+            "圣巴泰勒米",
+            "聖巴瑟米",
+            "サン・バルテルミー",
+            "생바르텔레미",
+            "St. Barthélemy",
+            "Saint-Barthélemy",
+            "San Bartolomé",
+            "Сен-Бартелеми",
+            "São Bartolomeu",
+        ],
+    ),
+    (
+        "BM",
+        [
+            "Bermuda",
+            "百慕大",
+            "百慕達",
+            "バミューダ",
+            "버뮤다",
+            "Bermuda",
+            "Bermudes",
+            "Bermudas",
+            "Бермудские о-ва",
+            "Bermudas",
+        ],
+    ),
+    (
+        "BN",
+        [
+            "Brunei",
+            "文莱",
+            "汶萊",
+            "ブルネイ",
+            "브루나이",
+            "Brunei Darussalam",
+            "Brunei",
+            "Brunéi",
+            "Бруней",
+            "Brunei",
+        ],
+    ),
+    (
+        "BO",
+        [
+            "Bolivia",
+            "玻利维亚",
+            "玻利維亞",
+            "ボリビア",
+            "볼리비아",
+            "Bolivien",
+            "Bolivie",
+            "Bolivia",
+            "Боливия",
+            "Bolívia",
+        ],
+    ),
+    (
+        "BQ",
+        [
+            "Caribbean Netherlands",
+            "荷属加勒比区",
+            "荷蘭加勒比區",
+            "オランダ領カリブ",
+            "네덜란드령 카리브",
+            "Karibische Niederlande",
+            "Pays-Bas caribéens",
+            "Caribe neerlandés",
+            "Бонэйр, Синт-Эстатиус и Саба",
+            "Países Baixos Caribenhos",
+        ],
+    ),
+    (
+        "BR",
+        [
+            "Brazil",
+            "巴西",
+            "巴西",
+            "ブラジル",
+            "브라질",
+            "Brasilien",
+            "Brésil",
+            "Brasil",
+            "Бразилия",
+            "Brasil",
+        ],
+    ),
+    (
+        "BS",
+        [
+            "Bahamas",
+            "巴哈马",
+            "巴哈馬",
+            "バハマ",
+            "바하마",
+            "Bahamas",
+            "Bahamas",
+            "Bahamas",
+            "Багамы",
+            "Bahamas",
+        ],
+    ),
+    (
+        "BT",
+        [
+            "Bhutan",
+            "不丹",
+            "不丹",
+            "ブータン",
+            "부탄",
+            "Bhutan",
+            "Bhoutan",
+            "Bután",
+            "Бутан",
+            "Butão",
+        ],
+    ),
+    (
+        "BV",
+        [
+            "Bouvet Island",
+            "布韦岛",
+            "布威島",
+            "ブーベ島",
+            "부베섬",
+            "Bouvetinsel",
+            "Île Bouvet",
+            "Isla Bouvet",
+            "о-в Буве",
+            "Ilha Bouvet",
+        ],
+    ),
+    (
+        "BW",
+        [
+            "Botswana",
+            "博茨瓦纳",
+            "波札那",
+            "ボツワナ",
+            "보츠와나",
+            "Botsuana",
+            "Botswana",
+            "Botsuana",
+            "Ботсвана",
+            "Botsuana",
+        ],
+    ),
+    (
+        "BY",
+        [
+            "Belarus",
+            "白俄罗斯",
+            "白俄羅斯",
+            "ベラルーシ",
+            "벨라루스",
+            "Belarus",
+            "Biélorussie",
+            "Bielorrusia",
+            "Беларусь",
+            "Bielorrússia",
+        ],
+    ),
+    (
+        "BZ",
+        [
+            "Belize",
+            "伯利兹",
+            "貝里斯",
+            "ベリーズ",
+            "벨리즈",
+            "Belize",
+            "Belize",
+            "Belice",
+            "Белиз",
+            "Belize",
+        ],
+    ),
+    (
+        "CA",
+        [
+            "Canada",
+            "加拿大",
+            "加拿大",
+            "カナダ",
+            "캐나다",
+            "Kanada",
+            "Canada",
+            "Canadá",
+            "Канада",
+            "Canadá",
+        ],
+    ),
+    (
+        "CC",
+        [
+            "Cocos (Keeling) Islands",
+            "科科斯（基林）群岛",
+            "科克斯（基靈）群島",
+            "ココス(キーリング)諸島",
+            "코코스 제도",
+            "Kokosinseln",
+            "Îles Cocos",
+            "Islas Cocos",
+            "Кокосовые о-ва",
+            "Ilhas Cocos (Keeling)",
+        ],
+    ),
+    (
+        "CD",
+        [
+            "Congo - Kinshasa",
+            "刚果（金）",
+            "剛果（金夏沙）",
+            "コンゴ民主共和国(キンシャサ)",
+            "콩고-킨샤사",
+            "Kongo-Kinshasa",
+            "Congo-Kinshasa",
+            "República Democrática del Congo",
+            "Конго - Киншаса",
+            "Congo - Kinshasa",
+        ],
+    ),
+    (
+        "CF",
+        [
+            "Central African Republic",
+            "中非共和国",
+            "中非共和國",
+            "中央アフリカ共和国",
+            "중앙 아프리카 공화국",
+            "Zentralafrikanische Republik",
+            "République centrafricaine",
+            "República Centroafricana",
+            "Центрально-Африканская Республика",
+            "República Centro-Africana",
+        ],
+    ),
+    (
+        "CG",
+        [
+            "Congo - Brazzaville",
+            "刚果（布）",
+            "剛果（布拉薩）",
+            "コンゴ共和国(ブラザビル)",
+            "콩고-브라자빌",
+            "Kongo-Brazzaville",
+            "Congo-Brazzaville",
+            "Congo",
+            "Конго - Браззавиль",
+            "República do Congo",
+        ],
+    ),
+    (
+        "CH",
+        [
+            "Switzerland",
+            "瑞士",
+            "瑞士",
+            "スイス",
+            "스위스",
+            "Schweiz",
+            "Suisse",
+            "Suiza",
+            "Швейцария",
+            "Suíça",
+        ],
+    ),
+    (
+        "CI",
+        [
+            "Côte d’Ivoire",
+            "科特迪瓦",
+            "象牙海岸",
+            "コートジボワール",
+            "코트디부아르",
+            "Côte d’Ivoire",
+            "Côte d’Ivoire",
+            "Côte d’Ivoire",
+            "Кот-д’Ивуар",
+            "Costa do Marfim",
+        ],
+    ),
+    (
+        "CK",
+        [
+            "Cook Islands",
+            "库克群岛",
+            "庫克群島",
+            "クック諸島",
+            "쿡 제도",
+            "Cookinseln",
+            "Îles Cook",
+            "Islas Cook",
+            "о-ва Кука",
+            "Ilhas Cook",
+        ],
+    ),
+    (
+        "CL",
+        [
+            "Chile", "智利", "智利", "チリ", "칠레", "Chile", "Chili", "Chile", "Чили", "Chile",
+        ],
+    ),
+    (
+        "CM",
+        [
+            "Cameroon",
+            "喀麦隆",
+            "喀麥隆",
+            "カメルーン",
+            "카메룬",
+            "Kamerun",
+            "Cameroun",
+            "Camerún",
+            "Камерун",
+            "Camarões",
+        ],
+    ),
+    (
+        "CN",
+        [
+            "China",
+            "中国",
+            "中國",
+            "中国",
+            "중국",
+            "China",
+            "Chine",
+            "China",
+            "Китай",
+            "China",
+        ],
+    ),
+    (
+        "CO",
+        [
+            "Colombia",
+            "哥伦比亚",
+            "哥倫比亞",
+            "コロンビア",
+            "콜롬비아",
+            "Kolumbien",
+            "Colombie",
+            "Colombia",
+            "Колумбия",
+            "Colômbia",
+        ],
+    ),
+    (
+        "CR",
+        [
+            "Costa Rica",
+            "哥斯达黎加",
+            "哥斯大黎加",
+            "コスタリカ",
+            "코스타리카",
+            "Costa Rica",
+            "Costa Rica",
+            "Costa Rica",
+            "Коста-Рика",
+            "Costa Rica",
+        ],
+    ),
+    (
+        "CU",
+        [
+            "Cuba",
+            "古巴",
+            "古巴",
+            "キューバ",
+            "쿠바",
+            "Kuba",
+            "Cuba",
+            "Cuba",
+            "Куба",
+            "Cuba",
+        ],
+    ),
+    (
+        "CV",
+        [
+            "Cape Verde",
+            "佛得角",
+            "維德角",
+            "カーボベルデ",
+            "카보베르데",
+            "Cabo Verde",
+            "Cap-Vert",
+            "Cabo Verde",
+            "Кабо-Верде",
+            "Cabo Verde",
+        ],
+    ),
+    (
+        "CW",
+        [
+            "Curaçao",
+            "库拉索",
+            "庫拉索",
+            "キュラソー",
+            "퀴라소",
+            "Curaçao",
+            "Curaçao",
+            "Curazao",
+            "Кюрасао",
+            "Curaçao",
+        ],
+    ),
+    (
+        "CX",
+        [
+            "Christmas Island",
+            "圣诞岛",
+            "聖誕島",
+            "クリスマス島",
+            "크리스마스섬",
+            "Weihnachtsinsel",
+            "Île Christmas",
+            "Isla de Navidad",
+            "о-в Рождества",
+            "Ilha Christmas",
+        ],
+    ),
+    (
+        "CY",
+        [
+            "Cyprus",
+            "塞浦路斯",
+            "賽普勒斯",
+            "キプロス",
+            "키프로스",
+            "Zypern",
+            "Chypre",
+            "Chipre",
+            "Кипр",
+            "Chipre",
+        ],
+    ),
+    (
+        "CZ",
+        [
+            "Czechia",
+            "捷克",
+            "捷克",
+            "チェコ",
+            "체코",
+            "Tschechien",
+            "Tchéquie",
+            "Chequia",
+            "Чехия",
+            "Tchéquia",
+        ],
+    ),
+    (
+        "DE",
+        [
+            "Germany",
+            "德国",
+            "德國",
+            "ドイツ",
+            "독일",
+            "Deutschland",
+            "Allemagne",
+            "Alemania",
+            "Германия",
+            "Alemanha",
+        ],
+    ),
+    (
+        "DJ",
+        [
+            "Djibouti",
+            "吉布提",
+            "吉布地",
+            "ジブチ",
+            "지부티",
+            "Dschibuti",
+            "Djibouti",
+            "Yibuti",
+            "Джибути",
+            "Djibuti",
+        ],
+    ),
+    (
+        "DK",
+        [
+            "Denmark",
+            "丹麦",
+            "丹麥",
+            "デンマーク",
+            "덴마크",
+            "Dänemark",
+            "Danemark",
+            "Dinamarca",
+            "Дания",
+            "Dinamarca",
+        ],
+    ),
+    (
+        "DM",
+        [
+            "Dominica",
+            "多米尼克",
+            "多米尼克",
+            "ドミニカ国",
+            "도미니카",
+            "Dominica",
+            "Dominique",
+            "Dominica",
+            "Доминика",
+            "Dominica",
+        ],
+    ),
+    (
+        "DO",
+        [
+            "Dominican Republic",
+            "多米尼加共和国",
+            "多明尼加共和國",
+            "ドミニカ共和国",
+            "도미니카 공화국",
+            "Dominikanische Republik",
+            "République dominicaine",
+            "República Dominicana",
+            "Доминиканская Республика",
+            "República Dominicana",
+        ],
+    ),
+    (
+        "DZ",
+        [
+            "Algeria",
+            "阿尔及利亚",
+            "阿爾及利亞",
+            "アルジェリア",
+            "알제리",
+            "Algerien",
+            "Algérie",
+            "Argelia",
+            "Алжир",
+            "Argélia",
+        ],
+    ),
+    (
+        "EC",
+        [
+            "Ecuador",
+            "厄瓜多尔",
+            "厄瓜多",
+            "エクアドル",
+            "에콰도르",
+            "Ecuador",
+            "Équateur",
+            "Ecuador",
+            "Эквадор",
+            "Equador",
+        ],
+    ),
+    (
+        "EE",
+        [
+            "Estonia",
+            "爱沙尼亚",
+            "愛沙尼亞",
+            "エストニア",
+            "에스토니아",
+            "Estland",
+            "Estonie",
+            "Estonia",
+            "Эстония",
+            "Estônia",
+        ],
+    ),
+    (
+        "EG",
+        [
+            "Egypt",
+            "埃及",
+            "埃及",
+            "エジプト",
+            "이집트",
+            "Ägypten",
+            "Égypte",
+            "Egipto",
+            "Египет",
+            "Egito",
+        ],
+    ),
+    (
+        "EH",
+        [
+            "Western Sahara",
+            "西撒哈拉",
+            "西撒哈拉",
+            "西サハラ",
+            "서사하라",
+            "Westsahara",
+            "Sahara occidental",
+            "Sáhara Occidental",
+            "Западная Сахара",
+            "Saara Ocidental",
+        ],
+    ),
+    (
+        "ER",
+        [
+            "Eritrea",
+            "厄立特里亚",
+            "厄利垂亞",
+            "エリトリア",
+            "에리트리아",
+            "Eritrea",
+            "Érythrée",
+            "Eritrea",
+            "Эритрея",
+            "Eritreia",
+        ],
+    ),
+    (
+        "ES",
+        [
+            "Spain",
+            "西班牙",
+            "西班牙",
+            "スペイン",
+            "스페인",
+            "Spanien",
+            "Espagne",
+            "España",
+            "Испания",
+            "Espanha",
+        ],
+    ),
+    (
+        "ET",
+        [
+            "Ethiopia",
+            "埃塞俄比亚",
+            "衣索比亞",
+            "エチオピア",
+            "에티오피아",
+            "Äthiopien",
+            "Éthiopie",
+            "Etiopía",
+            "Эфиопия",
+            "Etiópia",
+        ],
+    ),
+    (
+        "FI",
+        [
+            "Finland",
+            "芬兰",
+            "芬蘭",
+            "フィンランド",
+            "핀란드",
+            "Finnland",
+            "Finlande",
+            "Finlandia",
+            "Финляндия",
+            "Finlândia",
+        ],
+    ),
+    (
+        "FJ",
+        [
+            "Fiji",
+            "斐济",
+            "斐濟",
+            "フィジー",
+            "피지",
+            "Fidschi",
+            "Fidji",
+            "Fiyi",
+            "Фиджи",
+            "Fiji",
+        ],
+    ),
+    (
+        "FK",
+        [
+            "Falkland Islands",
+            "福克兰群岛",
+            "福克蘭群島",
+            "フォークランド諸島",
+            "포클랜드 제도",
+            "Falklandinseln",
+            "Îles Malouines",
+            "Islas Malvinas",
+            "Фолклендские о-ва",
+            "Ilhas Malvinas",
+        ],
+    ),
+    (
+        "FM",
+        [
+            "Micronesia",
+            "密克罗尼西亚",
+            "密克羅尼西亞",
+            "ミクロネシア連邦",
+            "미크로네시아",
+            "Mikronesien",
+            "Micronésie",
+            "Micronesia",
+            "Федеративные Штаты Микронезии",
+            "Micronésia",
+        ],
+    ),
+    (
+        "FO",
+        [
+            "Faroe Islands",
+            "法罗群岛",
+            "法羅群島",
+            "フェロー諸島",
+            "페로 제도",
+            "Färöer",
+            "Îles Féroé",
+            "Islas Feroe",
+            "Фарерские о-ва",
+            "Ilhas Faroé",
+        ],
+    ),
+    (
+        "FR",
+        [
+            "France",
+            "法国",
+            "法國",
+            "フランス",
+            "프랑스",
+            "Frankreich",
+            "France",
+            "Francia",
+            "Франция",
+            "França",
+        ],
+    ),
+    (
+        "GA",
+        [
+            "Gabon",
+            "加蓬",
+            "加彭",
+            "ガボン",
+            "가봉",
+            "Gabun",
+            "Gabon",
+            "Gabón",
+            "Габон",
+            "Gabão",
+        ],
+    ),
+    (
+        "GB",
+        [
+            "United Kingdom",
+            "英国",
+            "英國",
+            "イギリス",
+            "영국",
+            "Vereinigtes Königreich",
+            "Royaume-Uni",
+            "Reino Unido",
+            "Великобритания",
+            "Reino Unido",
+        ],
+    ),
+    (
+        "GD",
+        [
+            "Grenada",
+            "格林纳达",
+            "格瑞那達",
+            "グレナダ",
+            "그레나다",
+            "Grenada",
+            "Grenade",
+            "Granada",
+            "Гренада",
+            "Granada",
+        ],
+    ),
+    (
+        "GE",
+        [
+            "Georgia",
+            "格鲁吉亚",
+            "喬治亞",
+            "ジョージア",
+            "조지아",
+            "Georgien",
+            "Géorgie",
+            "Georgia",
+            "Грузия",
+            "Geórgia",
+        ],
+    ),
+    (
+        "GF",
+        [
+            "French Guiana",
+            "法属圭亚那",
+            "法屬圭亞那",
+            "仏領ギアナ",
+            "프랑스령 기아나",
+            "Französisch-Guayana",
+            "Guyane française",
+            "Guayana Francesa",
+            "Французская Гвиана",
+            "Guiana Francesa",
+        ],
+    ),
+    (
+        "GG",
+        [
+            "Guernsey",
+            "根西岛",
+            "根息",
+            "ガーンジー",
+            "건지",
+            "Guernsey",
+            "Guernesey",
+            "Guernesey",
+            "Гернси",
+            "Guernsey",
+        ],
+    ),
+    (
+        "GH",
+        [
+            "Ghana",
+            "加纳",
+            "迦納",
+            "ガーナ",
+            "가나",
+            "Ghana",
+            "Ghana",
+            "Ghana",
+            "Гана",
+            "Gana",
+        ],
+    ),
+    (
+        "GI",
+        [
+            "Gibraltar",
+            "直布罗陀",
+            "直布羅陀",
+            "ジブラルタル",
+            "지브롤터",
+            "Gibraltar",
+            "Gibraltar",
+            "Gibraltar",
+            "Гибралтар",
+            "Gibraltar",
+        ],
+    ),
+    (
+        "GL",
+        [
+            "Greenland",
+            "格陵兰",
+            "格陵蘭",
+            "グリーンランド",
+            "그린란드",
+            "Grönland",
+            "Groenland",
+            "Groenlandia",
+            "Гренландия",
+            "Groenlândia",
+        ],
+    ),
+    (
+        "GM",
+        [
+            "Gambia",
+            "冈比亚",
+            "甘比亞",
+            "ガンビア",
+            "감비아",
+            "Gambia",
+            "Gambie",
+            "Gambia",
+            "Гамбия",
+            "Gâmbia",
+        ],
+    ),
+    (
+        "GN",
+        [
+            "Guinea",
+            "几内亚",
+            "幾內亞",
+            "ギニア",
+            "기니",
+            "Guinea",
+            "Guinée",
+            "Guinea",
+            "Гвинея",
+            "Guiné",
+        ],
+    ),
+    (
+        "GP",
+        [
+            "Guadeloupe",
+            "瓜德罗普",
+            "瓜地洛普",
+            "グアドループ",
+            "과들루프",
+            "Guadeloupe",
+            "Guadeloupe",
+            "Guadalupe",
+            "Гваделупа",
+            "Guadalupe",
+        ],
+    ),
+    (
+        "GQ",
+        [
+            "Equatorial Guinea",
+            "赤道几内亚",
+            "赤道幾內亞",
+            "赤道ギニア",
+            "적도 기니",
+            "Äquatorialguinea",
+            "Guinée équatoriale",
+            "Guinea Ecuatorial",
+            "Экваториальная Гвинея",
+            "Guiné Equatorial",
+        ],
+    ),
+    (
+        "GR",
+        [
+            "Greece",
+            "希腊",
+            "希臘",
+            "ギリシャ",
+            "그리스",
+            "Griechenland",
+            "Grèce",
+            "Grecia",
+            "Греция",
+            "Grécia",
+        ],
+    ),
+    (
+        "GS",
+        [
+            "South Georgia & South Sandwich Islands",
+            "南乔治亚和南桑威奇群岛",
+            "南喬治亞與南三明治群島",
+            "サウスジョージア・サウスサンドウィッチ諸島",
+            "사우스조지아 사우스샌드위치 제도",
+            "Südgeorgien und die Südlichen Sandwichinseln",
+            "Géorgie du Sud-et-les Îles Sandwich du Sud",
+            "Islas Georgia del Sur y Sandwich del Sur",
+            "Южная Георгия и Южные Сандвичевы о-ва",
+            "Ilhas Geórgia do Sul e Sandwich do Sul",
+        ],
+    ),
+    (
+        "GT",
+        [
+            "Guatemala",
+            "危地马拉",
+            "瓜地馬拉",
+            "グアテマラ",
+            "과테말라",
+            "Guatemala",
+            "Guatemala",
+            "Guatemala",
+            "Гватемала",
+            "Guatemala",
+        ],
+    ),
+    (
+        "GU",
+        [
+            "Guam",
+            "关岛",
+            "關島",
+            "グアム",
+            "괌",
+            "Guam",
+            "Guam",
+            "Guam",
+            "Гуам",
+            "Guam",
+        ],
+    ),
+    (
+        "GW",
+        [
+            "Guinea-Bissau",
+            "几内亚比绍",
+            "幾內亞比索",
+            "ギニアビサウ",
+            "기니비사우",
+            "Guinea-Bissau",
+            "Guinée-Bissau",
+            "Guinea-Bisáu",
+            "Гвинея-Бисау",
+            "Guiné-Bissau",
+        ],
+    ),
+    (
+        "GY",
+        [
+            "Guyana",
+            "圭亚那",
+            "蓋亞那",
+            "ガイアナ",
+            "가이아나",
+            "Guyana",
+            "Guyana",
+            "Guyana",
+            "Гайана",
+            "Guiana",
+        ],
+    ),
+    (
+        "HK",
+        [
+            "Hong Kong",
+            "香港",
+            "香港",
+            "香港",
+            "홍콩",
+            "Hongkong",
+            "Hong Kong",
+            "Hong Kong",
+            "Гонконг",
+            "Hong Kong",
+        ],
+    ),
+    (
+        "HM",
+        [
+            "Heard & McDonald Islands",
+            "赫德岛和麦克唐纳群岛",
+            "赫德島及麥唐納群島",
+            "ハード島・マクドナルド諸島",
+            "허드 맥도널드 제도",
+            "Heard und McDonaldinseln",
+            "Îles Heard-et-MacDonald",
+            "Islas Heard y McDonald",
+            "о-ва Херд и Макдональд",
+            "Ilhas Heard e McDonald",
+        ],
+    ),
+    (
+        "HN",
+        [
+            "Honduras",
+            "洪都拉斯",
+            "宏都拉斯",
+            "ホンジュラス",
+            "온두라스",
+            "Honduras",
+            "Honduras",
+            "Honduras",
+            "Гондурас",
+            "Honduras",
+        ],
+    ),
+    (
+        "HR",
+        [
+            "Croatia",
+            "克罗地亚",
+            "克羅埃西亞",
+            "クロアチア",
+            "크로아티아",
+            "Kroatien",
+            "Croatie",
+            "Croacia",
+            "Хорватия",
+            "Croácia",
+        ],
+    ),
+    (
+        "HT",
+        [
+            "Haiti",
+            "海地",
+            "海地",
+            "ハイチ",
+            "아이티",
+            "Haiti",
+            "Haïti",
+            "Haití",
+            "Гаити",
+            "Haiti",
+        ],
+    ),
+    (
+        "HU",
+        [
+            "Hungary",
+            "匈牙利",
+            "匈牙利",
+            "ハンガリー",
+            "헝가리",
+            "Ungarn",
+            "Hongrie",
+            "Hungría",
+            "Венгрия",
+            "Hungria",
+        ],
+    ),
+    (
+        "ID",
+        [
+            "Indonesia",
+            "印度尼西亚",
+            "印尼",
+            "インドネシア",
+            "인도네시아",
+            "Indonesien",
+            "Indonésie",
+            "Indonesia",
+            "Индонезия",
+            "Indonésia",
+        ],
+    ),
+    (
+        "IE",
+        [
+            "Ireland",
+            "爱尔兰",
+            "愛爾蘭",
+            "アイルランド",
+            "아일랜드",
+            "Irland",
+            "Irlande",
+            "Irlanda",
+            "Ирландия",
+            "Irlanda",
+        ],
+    ),
+    (
+        "IL",
+        [
+            "Israel",
+            "以色列",
+            "以色列",
+            "イスラエル",
+            "이스라엘",
+            "Israel",
+            "Israël",
+            "Israel",
+            "Израиль",
+            "Israel",
+        ],
+    ),
+    (
+        "IM",
+        [
+            "Isle of Man",
+            "马恩岛",
+            "曼島",
+            "マン島",
+            "맨섬",
+            "Isle of Man",
+            "Île de Man",
+            "Isla de Man",
+            "о-в Мэн",
+            "Ilha de Man",
+        ],
+    ),
+    (
+        "IN",
+        [
+            "India",
+            "印度",
+            "印度",
+            "インド",
+            "인도",
+            "Indien",
+            "Inde",
+            "India",
+            "Индия",
+            "Índia",
+        ],
+    ),
+    (
+        "IO",
+        [
+            "British Indian Ocean Territory",
+            "英属印度洋领地",
+            "英屬印度洋領地",
+            "英領インド洋地域",
+            "영국령 인도양 지역",
+            "Britisches Territorium im Indischen Ozean",
+            "Territoire britannique de l’océan Indien",
+            "Territorio Británico del Océano Índico",
+            "Британская территория в Индийском океане",
+            "Território Britânico do Oceano Índico",
+        ],
+    ),
+    (
+        "IQ",
+        [
+            "Iraq",
+            "伊拉克",
+            "伊拉克",
+            "イラク",
+            "이라크",
+            "Irak",
+            "Irak",
+            "Irak",
+            "Ирак",
+            "Iraque",
+        ],
+    ),
+    (
+        "IR",
+        [
+            "Iran",
+            "伊朗",
+            "伊朗",
+            "イラン",
+            "이란",
+            "Iran",
+            "Iran",
+            "Irán",
+            "Иран",
+            "Irã",
+        ],
+    ),
+    (
+        "IS",
+        [
+            "Iceland",
+            "冰岛",
+            "冰島",
+            "アイスランド",
+            "아이슬란드",
+            "Island",
+            "Islande",
+            "Islandia",
+            "Исландия",
+            "Islândia",
+        ],
+    ),
+    (
+        "IT",
+        [
+            "Italy",
+            "意大利",
+            "義大利",
+            "イタリア",
+            "이탈리아",
+            "Italien",
+            "Italie",
+            "Italia",
+            "Италия",
+            "Itália",
+        ],
+    ),
+    (
+        "JE",
+        [
+            "Jersey",
+            "泽西岛",
+            "澤西島",
+            "ジャージー",
+            "저지",
+            "Jersey",
+            "Jersey",
+            "Jersey",
+            "Джерси",
+            "Jersey",
+        ],
+    ),
+    (
+        "JM",
+        [
+            "Jamaica",
+            "牙买加",
+            "牙買加",
+            "ジャマイカ",
+            "자메이카",
+            "Jamaika",
+            "Jamaïque",
+            "Jamaica",
+            "Ямайка",
+            "Jamaica",
+        ],
+    ),
+    (
+        "JO",
+        [
+            "Jordan",
+            "约旦",
+            "約旦",
+            "ヨルダン",
+            "요르단",
+            "Jordanien",
+            "Jordanie",
+            "Jordania",
+            "Иордания",
+            "Jordânia",
+        ],
+    ),
+    (
+        "JP",
+        [
+            "Japan",
+            "日本",
+            "日本",
+            "日本",
+            "일본",
+            "Japan",
+            "Japon",
+            "Japón",
+            "Япония",
+            "Japão",
+        ],
+    ),
+    (
+        "KE",
+        [
+            "Kenya",
+            "肯尼亚",
+            "肯亞",
+            "ケニア",
+            "케냐",
+            "Kenia",
+            "Kenya",
+            "Kenia",
+            "Кения",
+            "Quênia",
+        ],
+    ),
+    (
+        "KG",
+        [
+            "Kyrgyzstan",
+            "吉尔吉斯斯坦",
+            "吉爾吉斯",
+            "キルギス",
+            "키르기스스탄",
+            "Kirgisistan",
+            "Kirghizstan",
+            "Kirguistán",
+            "Киргизия",
+            "Quirguistão",
+        ],
+    ),
+    (
+        "KH",
+        [
+            "Cambodia",
+            "柬埔寨",
+            "柬埔寨",
+            "カンボジア",
+            "캄보디아",
+            "Kambodscha",
+            "Cambodge",
+            "Camboya",
+            "Камбоджа",
+            "Camboja",
+        ],
+    ),
+    (
+        "KI",
+        [
+            "Kiribati",
+            "基里巴斯",
+            "吉里巴斯",
+            "キリバス",
+            "키리바시",
+            "Kiribati",
+            "Kiribati",
+            "Kiribati",
+            "Кирибати",
+            "Quiribati",
+        ],
+    ),
+    (
+        "KM",
+        [
+            "Comoros",
+            "科摩罗",
+            "葛摩",
+            "コモロ",
+            "코모로",
+            "Komoren",
+            "Comores",
+            "Comoras",
+            "Коморы",
+            "Comores",
+        ],
+    ),
+    (
+        "KN",
+        [
+            "St. Kitts & Nevis",
+            "圣基茨和尼维斯",
+            "聖克里斯多福及尼維斯",
+            "セントクリストファー・ネーヴィス",
+            "세인트키츠 네비스",
+            "St. Kitts und Nevis",
+            "Saint-Christophe-et-Niévès",
+            "San Cristóbal y Nieves",
+            "Сент-Китс и Невис",
+            "São Cristóvão e Névis",
+        ],
+    ),
+    (
+        "KP",
+        [
+            "North Korea",
+            "朝鲜",
+            "北韓",
+            "北朝鮮",
+            "북한",
+            "Nordkorea",
+            "Corée du Nord",
+            "Corea del Norte",
+            "КНДР",
+            "Coreia do Norte",
+        ],
+    ),
+    (
+        "KR",
+        [
+            "South Korea",
+            "韩国",
+            "南韓",
+            "韓国",
+            "대한민국",
+            "Südkorea",
+            "Corée du Sud",
+            "Corea del Sur",
+            "Республика Корея",
+            "Coreia do Sul",
+        ],
+    ),
+    (
+        "KW",
+        [
+            "Kuwait",
+            "科威特",
+            "科威特",
+            "クウェート",
+            "쿠웨이트",
+            "Kuwait",
+            "Koweït",
+            "Kuwait",
+            "Кувейт",
+            "Kuwait",
+        ],
+    ),
+    (
+        "KY",
+        [
+            "Cayman Islands",
+            "开曼群岛",
+            "開曼群島",
+            "ケイマン諸島",
+            "케이맨 제도",
+            "Kaimaninseln",
+            "Îles Caïmans",
+            "Islas Caimán",
+            "о-ва Кайман",
+            "Ilhas Cayman",
+        ],
+    ),
+    (
+        "KZ",
+        [
+            "Kazakhstan",
+            "哈萨克斯坦",
+            "哈薩克",
+            "カザフスタン",
+            "카자흐스탄",
+            "Kasachstan",
+            "Kazakhstan",
+            "Kazajistán",
+            "Казахстан",
+            "Cazaquistão",
+        ],
+    ),
+    (
+        "LA",
+        [
+            "Laos",
+            "老挝",
+            "寮國",
+            "ラオス",
+            "라오스",
+            "Laos",
+            "Laos",
+            "Laos",
+            "Лаос",
+            "Laos",
+        ],
+    ),
+    (
+        "LB",
+        [
+            "Lebanon",
+            "黎巴嫩",
+            "黎巴嫩",
+            "レバノン",
+            "레바논",
+            "Libanon",
+            "Liban",
+            "Líbano",
+            "Ливан",
+            "Líbano",
+        ],
+    ),
+    (
+        "LC",
+        [
+            "St. Lucia",
+            "圣卢西亚",
+            "聖露西亞",
+            "セントルシア",
+            "세인트루시아",
+            "St. Lucia",
+            "Sainte-Lucie",
+            "Santa Lucía",
+            "Сент-Люсия",
+            "Santa Lúcia",
+        ],
+    ),
+    (
+        "LI",
+        [
+            "Liechtenstein",
+            "列支敦士登",
+            "列支敦斯登",
+            "リヒテンシュタイン",
+            "리히텐슈타인",
+            "Liechtenstein",
+            "Liechtenstein",
+            "Liechtenstein",
+            "Лихтенштейн",
+            "Liechtenstein",
+        ],
+    ),
+    (
+        "LK",
+        [
+            "Sri Lanka",
+            "斯里兰卡",
+            "斯里蘭卡",
+            "スリランカ",
+            "스리랑카",
+            "Sri Lanka",
+            "Sri Lanka",
+            "Sri Lanka",
+            "Шри-Ланка",
+            "Sri Lanka",
+        ],
+    ),
+    (
+        "LR",
+        [
+            "Liberia",
+            "利比里亚",
+            "賴比瑞亞",
+            "リベリア",
+            "라이베리아",
+            "Liberia",
+            "Liberia",
+            "Liberia",
+            "Либерия",
+            "Libéria",
+        ],
+    ),
+    (
+        "LS",
+        [
+            "Lesotho",
+            "莱索托",
+            "賴索托",
+            "レソト",
+            "레소토",
+            "Lesotho",
+            "Lesotho",
+            "Lesoto",
+            "Лесото",
+            "Lesoto",
+        ],
+    ),
+    (
+        "LT",
+        [
+            "Lithuania",
+            "立陶宛",
+            "立陶宛",
+            "リトアニア",
+            "리투아니아",
+            "Litauen",
+            "Lituanie",
+            "Lituania",
+            "Литва",
+            "Lituânia",
+        ],
+    ),
+    (
+        "LU",
+        [
+            "Luxembourg",
+            "卢森堡",
+            "盧森堡",
+            "ルクセンブルク",
+            "룩셈부르크",
+            "Luxemburg",
+            "Luxembourg",
+            "Luxemburgo",
+            "Люксембург",
+            "Luxemburgo",
+        ],
+    ),
+    (
+        "LV",
+        [
+            "Latvia",
+            "拉脱维亚",
+            "拉脫維亞",
+            "ラトビア",
+            "라트비아",
+            "Lettland",
+            "Lettonie",
+            "Letonia",
+            "Латвия",
+            "Letônia",
+        ],
+    ),
+    (
+        "LY",
+        [
+            "Libya",
+            "利比亚",
+            "利比亞",
+            "リビア",
+            "리비아",
+            "Libyen",
+            "Libye",
+            "Libia",
+            "Ливия",
+            "Líbia",
+        ],
+    ),
+    (
+        "MA",
+        [
+            "Morocco",
+            "摩洛哥",
+            "摩洛哥",
+            "モロッコ",
+            "모로코",
+            "Marokko",
+            "Maroc",
+            "Marruecos",
+            "Марокко",
+            "Marrocos",
+        ],
+    ),
+    (
+        "MC",
+        [
+            "Monaco",
+            "摩纳哥",
+            "摩納哥",
+            "モナコ",
+            "모나코",
+            "Monaco",
+            "Monaco",
+            "Mónaco",
+            "Монако",
+            "Mônaco",
+        ],
+    ),
+    (
+        "MD",
+        [
+            "Moldova",
+            "摩尔多瓦",
+            "摩爾多瓦",
+            "モルドバ",
+            "몰도바",
+            "Republik Moldau",
+            "Moldavie",
+            "Moldavia",
+            "Молдова",
+            "Moldávia",
+        ],
+    ),
+    (
+        "ME",
+        [
+            "Montenegro",
+            "黑山",
+            "蒙特內哥羅",
+            "モンテネグロ",
+            "몬테네그로",
+            "Montenegro",
+            "Monténégro",
+            "Montenegro",
+            "Черногория",
+            "Montenegro",
+        ],
+    ),
+    (
+        "MF",
+        [
+            "St. Martin",
+            "法属圣马丁",
+            "法屬聖馬丁",
+            "サン・マルタン",
+            "생마르탱",
+            "St. Martin",
+            "Saint-Martin",
+            "San Martín",
+            "Сен-Мартен",
+            "São Martinho",
+        ],
+    ),
+    (
+        "MG",
+        [
+            "Madagascar",
+            "马达加斯加",
+            "馬達加斯加",
+            "マダガスカル",
+            "마다가스카르",
+            "Madagaskar",
+            "Madagascar",
+            "Madagascar",
+            "Мадагаскар",
+            "Madagascar",
+        ],
+    ),
+    (
+        "MH",
+        [
+            "Marshall Islands",
+            "马绍尔群岛",
+            "馬紹爾群島",
+            "マーシャル諸島",
+            "마셜 제도",
+            "Marshallinseln",
+            "Îles Marshall",
+            "Islas Marshall",
+            "Маршалловы о-ва",
+            "Ilhas Marshall",
+        ],
+    ),
+    (
+        "MK",
+        [
+            "North Macedonia",
+            "北马其顿",
+            "北馬其頓",
+            "北マケドニア",
+            "북마케도니아",
+            "Nordmazedonien",
+            "Macédoine du Nord",
+            "Macedonia del Norte",
+            "Северная Македония",
+            "Macedônia do Norte",
+        ],
+    ),
+    (
+        "ML",
+        [
+            "Mali", "马里", "馬利", "マリ", "말리", "Mali", "Mali", "Mali", "Мали", "Mali",
+        ],
+    ),
+    (
+        "MM",
+        [
+            "Myanmar (Burma)",
+            "缅甸",
+            "緬甸",
+            "ミャンマー (ビルマ)",
+            "미얀마",
+            "Myanmar",
+            "Myanmar (Birmanie)",
+            "Myanmar (Birmania)",
+            "Мьянма (Бирма)",
+            "Mianmar (Birmânia)",
+        ],
+    ),
+    (
+        "MN",
+        [
+            "Mongolia",
+            "蒙古",
+            "蒙古",
+            "モンゴル",
+            "몽골",
+            "Mongolei",
+            "Mongolie",
+            "Mongolia",
+            "Монголия",
+            "Mongólia",
+        ],
+    ),
+    (
+        "MO",
+        [
+            "Macao",
+            "澳门",
+            "澳門",
+            "マカオ",
+            "마카오",
+            "Macau",
+            "Macao",
+            "Macao",
+            "Макао",
+            "Macau",
+        ],
+    ),
+    (
+        "MP",
+        [
+            "Northern Mariana Islands",
+            "北马里亚纳群岛",
+            "北馬利安納群島",
+            "北マリアナ諸島",
+            "북마리아나제도",
+            "Nördliche Marianen",
+            "Îles Mariannes du Nord",
+            "Islas Marianas del Norte",
+            "Северные Марианские о-ва",
+            "Ilhas Marianas do Norte",
+        ],
+    ),
+    (
+        "MQ",
+        [
+            "Martinique",
+            "马提尼克",
+            "馬丁尼克",
+            "マルティニーク",
+            "마르티니크",
+            "Martinique",
+            "Martinique",
+            "Martinica",
+            "Мартиника",
+            "Martinica",
+        ],
+    ),
+    (
+        "MR",
+        [
+            "Mauritania",
+            "毛里塔尼亚",
+            "茅利塔尼亞",
+            "モーリタニア",
+            "모리타니",
+            "Mauretanien",
+            "Mauritanie",
+            "Mauritania",
+            "Мавритания",
+            "Mauritânia",
+        ],
+    ),
+    (
+        "MS",
+        [
+            "Montserrat",
+            "蒙特塞拉特",
+            "蒙哲臘",
+            "モントセラト",
+            "몬트세라트",
+            "Montserrat",
+            "Montserrat",
+            "Montserrat",
+            "Монтсеррат",
+            "Montserrat",
+        ],
+    ),
+    (
+        "MT",
+        [
+            "Malta",
+            "马耳他",
+            "馬爾他",
+            "マルタ",
+            "몰타",
+            "Malta",
+            "Malte",
+            "Malta",
+            "Мальта",
+            "Malta",
+        ],
+    ),
+    (
+        "MU",
+        [
+            "Mauritius",
+            "毛里求斯",
+            "模里西斯",
+            "モーリシャス",
+            "모리셔스",
+            "Mauritius",
+            "Maurice",
+            "Mauricio",
+            "Маврикий",
+            "Maurício",
+        ],
+    ),
+    (
+        "MV",
+        [
+            "Maldives",
+            "马尔代夫",
+            "馬爾地夫",
+            "モルディブ",
+            "몰디브",
+            "Malediven",
+            "Maldives",
+            "Maldivas",
+            "Мальдивы",
+            "Maldivas",
+        ],
+    ),
+    (
+        "MW",
+        [
+            "Malawi",
+            "马拉维",
+            "馬拉威",
+            "マラウイ",
+            "말라위",
+            "Malawi",
+            "Malawi",
+            "Malaui",
+            "Малави",
+            "Malaui",
+        ],
+    ),
+    (
+        "MX",
+        [
+            "Mexico",
+            "墨西哥",
+            "墨西哥",
+            "メキシコ",
+            "멕시코",
+            "Mexiko",
+            "Mexique",
+            "México",
+            "Мексика",
+            "México",
+        ],
+    ),
+    (
+        "MY",
+        [
+            "Malaysia",
+            "马来西亚",
+            "馬來西亞",
+            "マレーシア",
+            "말레이시아",
+            "Malaysia",
+            "Malaisie",
+            "Malasia",
+            "Малайзия",
+            "Malásia",
+        ],
+    ),
+    (
+        "MZ",
+        [
+            "Mozambique",
+            "莫桑比克",
+            "莫三比克",
+            "モザンビーク",
+            "모잠비크",
+            "Mosambik",
+            "Mozambique",
+            "Mozambique",
+            "Мозамбик",
+            "Moçambique",
+        ],
+    ),
+    (
+        "NA",
+        [
+            "Namibia",
+            "纳米比亚",
+            "納米比亞",
+            "ナミビア",
+            "나미비아",
+            "Namibia",
+            "Namibie",
+            "Namibia",
+            "Намибия",
+            "Namíbia",
+        ],
+    ),
+    (
+        "NC",
+        [
+            "New Caledonia",
+            "新喀里多尼亚",
+            "新喀里多尼亞",
+            "ニューカレドニア",
+            "뉴칼레도니아",
+            "Neukaledonien",
+            "Nouvelle-Calédonie",
+            "Nueva Caledonia",
+            "Новая Каледония",
+            "Nova Caledônia",
+        ],
+    ),
+    (
+        "NE",
+        [
+            "Niger",
+            "尼日尔",
+            "尼日",
+            "ニジェール",
+            "니제르",
+            "Niger",
+            "Niger",
+            "Níger",
+            "Нигер",
+            "Níger",
+        ],
+    ),
+    (
+        "NF",
+        [
+            "Norfolk Island",
+            "诺福克岛",
+            "諾福克島",
+            "ノーフォーク島",
+            "노퍽섬",
+            "Norfolkinsel",
+            "Île Norfolk",
+            "Isla Norfolk",
+            "о-в Норфолк",
+            "Ilha Norfolk",
+        ],
+    ),
+    (
+        "NG",
+        [
+            "Nigeria",
+            "尼日利亚",
+            "奈及利亞",
+            "ナイジェリア",
+            "나이지리아",
+            "Nigeria",
+            "Nigeria",
+            "Nigeria",
+            "Нигерия",
+            "Nigéria",
+        ],
+    ),
+    (
+        "NI",
+        [
+            "Nicaragua",
+            "尼加拉瓜",
+            "尼加拉瓜",
+            "ニカラグア",
+            "니카라과",
+            "Nicaragua",
+            "Nicaragua",
+            "Nicaragua",
+            "Никарагуа",
+            "Nicarágua",
+        ],
+    ),
+    (
+        "NL",
+        [
+            "Netherlands",
+            "荷兰",
+            "荷蘭",
+            "オランダ",
+            "네덜란드",
+            "Niederlande",
+            "Pays-Bas",
+            "Países Bajos",
+            "Нидерланды",
+            "Países Baixos",
+        ],
+    ),
+    (
+        "NO",
+        [
+            "Norway",
+            "挪威",
+            "挪威",
+            "ノルウェー",
+            "노르웨이",
+            "Norwegen",
+            "Norvège",
+            "Noruega",
+            "Норвегия",
+            "Noruega",
+        ],
+    ),
+    (
+        "NP",
+        [
+            "Nepal",
+            "尼泊尔",
+            "尼泊爾",
+            "ネパール",
+            "네팔",
+            "Nepal",
+            "Népal",
+            "Nepal",
+            "Непал",
+            "Nepal",
+        ],
+    ),
+    (
+        "NR",
+        [
+            "Nauru",
+            "瑙鲁",
+            "諾魯",
+            "ナウル",
+            "나우루",
+            "Nauru",
+            "Nauru",
+            "Nauru",
+            "Науру",
+            "Nauru",
+        ],
+    ),
+    (
+        "NU",
+        [
+            "Niue",
+            "纽埃",
+            "紐埃島",
+            "ニウエ",
+            "니우에",
+            "Niue",
+            "Niue",
+            "Niue",
+            "Ниуэ",
+            "Niue",
+        ],
+    ),
+    (
+        "NZ",
+        [
+            "New Zealand",
+            "新西兰",
+            "紐西蘭",
+            "ニュージーランド",
+            "뉴질랜드",
+            "Neuseeland",
+            "Nouvelle-Zélande",
+            "Nueva Zelanda",
+            "Новая Зеландия",
+            "Nova Zelândia",
+        ],
+    ),
+    (
+        "OM",
+        [
+            "Oman",
+            "阿曼",
+            "阿曼",
+            "オマーン",
+            "오만",
+            "Oman",
+            "Oman",
+            "Omán",
+            "Оман",
+            "Omã",
+        ],
+    ),
+    (
+        "PA",
+        [
+            "Panama",
+            "巴拿马",
+            "巴拿馬",
+            "パナマ",
+            "파나마",
+            "Panama",
+            "Panama",
+            "Panamá",
+            "Панама",
+            "Panamá",
+        ],
+    ),
+    (
+        "PE",
+        [
+            "Peru",
+            "秘鲁",
+            "秘魯",
+            "ペルー",
+            "페루",
+            "Peru",
+            "Pérou",
+            "Perú",
+            "Перу",
+            "Peru",
+        ],
+    ),
+    (
+        "PF",
+        [
+            "French Polynesia",
+            "法属波利尼西亚",
+            "法屬玻里尼西亞",
+            "仏領ポリネシア",
+            "프랑스령 폴리네시아",
+            "Französisch-Polynesien",
+            "Polynésie française",
+            "Polinesia Francesa",
+            "Французская Полинезия",
+            "Polinésia Francesa",
+        ],
+    ),
+    (
+        "PG",
+        [
+            "Papua New Guinea",
+            "巴布亚新几内亚",
+            "巴布亞紐幾內亞",
+            "パプアニューギニア",
+            "파푸아뉴기니",
+            "Papua-Neuguinea",
+            "Papouasie-Nouvelle-Guinée",
+            "Papúa Nueva Guinea",
+            "Папуа — Новая Гвинея",
+            "Papua-Nova Guiné",
+        ],
+    ),
+    (
+        "PH",
+        [
+            "Philippines",
+            "菲律宾",
+            "菲律賓",
+            "フィリピン",
+            "필리핀",
+            "Philippinen",
+            "Philippines",
+            "Filipinas",
+            "Филиппины",
+            "Filipinas",
+        ],
+    ),
+    (
+        "PK",
+        [
+            "Pakistan",
+            "巴基斯坦",
+            "巴基斯坦",
+            "パキスタン",
+            "파키스탄",
+            "Pakistan",
+            "Pakistan",
+            "Pakistán",
+            "Пакистан",
+            "Paquistão",
+        ],
+    ),
+    (
+        "PL",
+        [
+            "Poland",
+            "波兰",
+            "波蘭",
+            "ポーランド",
+            "폴란드",
+            "Polen",
+            "Pologne",
+            "Polonia",
+            "Польша",
+            "Polônia",
+        ],
+    ),
+    (
+        "PM",
+        [
+            "St. Pierre & Miquelon",
+            "圣皮埃尔和密克隆群岛",
+            "聖皮埃與密克隆群島",
+            "サンピエール島・ミクロン島",
+            "생피에르 미클롱",
+            "St. Pierre und Miquelon",
+            "Saint-Pierre-et-Miquelon",
+            "San Pedro y Miquelón",
+            "Сен-Пьер и Микелон",
+            "São Pedro e Miquelão",
+        ],
+    ),
+    (
+        "PN",
+        [
+            "Pitcairn Islands",
+            "皮特凯恩群岛",
+            "皮特肯群島",
+            "ピトケアン諸島",
+            "핏케언 제도",
+            "Pitcairninseln",
+            "Îles Pitcairn",
+            "Islas Pitcairn",
+            "о-ва Питкэрн",
+            "Ilhas Pitcairn",
+        ],
+    ),
+    (
+        "PR",
+        [
+            "Puerto Rico",
+            "波多黎各",
+            "波多黎各",
+            "プエルトリコ",
+            "푸에르토리코",
+            "Puerto Rico",
+            "Porto Rico",
+            "Puerto Rico",
+            "Пуэрто-Рико",
+            "Porto Rico",
+        ],
+    ),
+    (
+        "PS",
+        [
+            "Palestinian Territories",
+            "巴勒斯坦领土",
+            "巴勒斯坦自治區",
+            "パレスチナ自治区",
+            "팔레스타인 지구",
+            "Palästinensische Autonomiegebiete",
+            "Territoires palestiniens",
+            "Territorios Palestinos",
+            "Палестинские территории",
+            "Territórios palestinos",
+        ],
+    ),
+    (
+        "PT",
+        [
+            "Portugal",
+            "葡萄牙",
+            "葡萄牙",
+            "ポルトガル",
+            "포르투갈",
+            "Portugal",
+            "Portugal",
+            "Portugal",
+            "Португалия",
+            "Portugal",
+        ],
+    ),
+    (
+        "PW",
+        [
+            "Palau",
+            "帕劳",
+            "帛琉",
+            "パラオ",
+            "팔라우",
+            "Palau",
+            "Palaos",
+            "Palaos",
+            "Палау",
+            "Palau",
+        ],
+    ),
+    (
+        "PY",
+        [
+            "Paraguay",
+            "巴拉圭",
+            "巴拉圭",
+            "パラグアイ",
+            "파라과이",
+            "Paraguay",
+            "Paraguay",
+            "Paraguay",
+            "Парагвай",
+            "Paraguai",
+        ],
+    ),
+    (
+        "QA",
+        [
+            "Qatar",
+            "卡塔尔",
+            "卡達",
+            "カタール",
+            "카타르",
+            "Katar",
+            "Qatar",
+            "Catar",
+            "Катар",
+            "Catar",
+        ],
+    ),
+    (
+        "RE",
+        [
+            "Réunion",
+            "留尼汪",
+            "留尼旺",
+            "レユニオン",
+            "레위니옹",
+            "Réunion",
+            "La Réunion",
+            "Reunión",
+            "Реюньон",
+            "Reunião",
+        ],
+    ),
+    (
+        "RO",
+        [
+            "Romania",
+            "罗马尼亚",
+            "羅馬尼亞",
+            "ルーマニア",
+            "루마니아",
+            "Rumänien",
+            "Roumanie",
+            "Rumanía",
+            "Румыния",
+            "Romênia",
+        ],
+    ),
+    (
+        "RS",
+        [
+            "Serbia",
+            "塞尔维亚",
+            "塞爾維亞",
+            "セルビア",
+            "세르비아",
+            "Serbien",
+            "Serbie",
+            "Serbia",
+            "Сербия",
+            "Sérvia",
+        ],
+    ),
+    (
+        "RU",
+        [
+            "Russia",
+            "俄罗斯",
+            "俄羅斯",
+            "ロシア",
+            "러시아",
+            "Russland",
+            "Russie",
+            "Rusia",
+            "Россия",
+            "Rússia",
+        ],
+    ),
+    (
+        "RW",
+        [
+            "Rwanda",
+            "卢旺达",
+            "盧安達",
+            "ルワンダ",
+            "르완다",
+            "Ruanda",
+            "Rwanda",
+            "Ruanda",
+            "Руанда",
+            "Ruanda",
+        ],
+    ),
+    (
+        "SA",
+        [
+            "Saudi Arabia",
+            "沙特阿拉伯",
+            "沙烏地阿拉伯",
+            "サウジアラビア",
+            "사우디아라비아",
+            "Saudi-Arabien",
+            "Arabie saoudite",
+            "Arabia Saudí",
+            "Саудовская Аравия",
+            "Arábia Saudita",
+        ],
+    ),
+    (
+        "SB",
+        [
+            "Solomon Islands",
+            "所罗门群岛",
+            "索羅門群島",
+            "ソロモン諸島",
+            "솔로몬 제도",
+            "Salomonen",
+            "Îles Salomon",
+            "Islas Salomón",
+            "Соломоновы о-ва",
+            "Ilhas Salomão",
+        ],
+    ),
+    (
+        "SC",
+        [
+            "Seychelles",
+            "塞舌尔",
+            "塞席爾",
+            "セーシェル",
+            "세이셸",
+            "Seychellen",
+            "Seychelles",
+            "Seychelles",
+            "Сейшельские о-ва",
+            "Seicheles",
+        ],
+    ),
+    (
+        "SD",
+        [
+            "Sudan",
+            "苏丹",
+            "蘇丹",
+            "スーダン",
+            "수단",
+            "Sudan",
+            "Soudan",
+            "Sudán",
+            "Судан",
+            "Sudão",
+        ],
+    ),
+    (
+        "SE",
+        [
+            "Sweden",
+            "瑞典",
+            "瑞典",
+            "スウェーデン",
+            "스웨덴",
+            "Schweden",
+            "Suède",
+            "Suecia",
+            "Швеция",
+            "Suécia",
+        ],
+    ),
+    (
+        "SG",
+        [
+            "Singapore",
+            "新加坡",
+            "新加坡",
+            "シンガポール",
+            "싱가포르",
+            "Singapur",
+            "Singapour",
+            "Singapur",
+            "Сингапур",
+            "Singapura",
+        ],
+    ),
+    (
+        "SH",
+        [
+            "St. Helena",
+            "圣赫勒拿",
+            "聖赫勒拿島",
+            "セントヘレナ",
+            "세인트헬레나",
+            "St. Helena",
+            "Sainte-Hélène",
+            "Santa Elena",
+            "о-в Св. Елены",
+            "Santa Helena",
+        ],
+    ),
+    (
+        "SI",
+        [
+            "Slovenia",
+            "斯洛文尼亚",
+            "斯洛維尼亞",
+            "スロベニア",
+            "슬로베니아",
+            "Slowenien",
+            "Slovénie",
+            "Eslovenia",
+            "Словения",
+            "Eslovênia",
+        ],
+    ),
+    (
+        "SJ",
+        [
+            "Svalbard & Jan Mayen",
+            "斯瓦尔巴和扬马延",
+            "挪威屬斯瓦巴及尖棉",
+            "スバールバル諸島・ヤンマイエン島",
+            "스발바르제도-얀마웬섬",
+            "Spitzbergen und Jan Mayen",
+            "Svalbard et Jan Mayen",
+            "Svalbard y Jan Mayen",
+            "Шпицберген и Ян-Майен",
+            "Svalbard e Jan Mayen",
+        ],
+    ),
+    (
+        "SK",
+        [
+            "Slovakia",
+            "斯洛伐克",
+            "斯洛伐克",
+            "スロバキア",
+            "슬로바키아",
+            "Slowakei",
+            "Slovaquie",
+            "Eslovaquia",
+            "Словакия",
+            "Eslováquia",
+        ],
+    ),
+    (
+        "SL",
+        [
+            "Sierra Leone",
+            "塞拉利昂",
+            "獅子山",
+            "シエラレオネ",
+            "시에라리온",
+            "Sierra Leone",
+            "Sierra Leone",
+            "Sierra Leona",
+            "Сьерра-Леоне",
+            "Serra Leoa",
+        ],
+    ),
+    (
+        "SM",
+        [
+            "San Marino",
+            "圣马力诺",
+            "聖馬利諾",
+            "サンマリノ",
+            "산마리노",
+            "San Marino",
+            "Saint-Marin",
+            "San Marino",
+            "Сан-Марино",
+            "San Marino",
+        ],
+    ),
+    (
+        "SN",
+        [
+            "Senegal",
+            "塞内加尔",
+            "塞內加爾",
+            "セネガル",
+            "세네갈",
+            "Senegal",
+            "Sénégal",
+            "Senegal",
+            "Сенегал",
+            "Senegal",
+        ],
+    ),
+    (
+        "SO",
+        [
+            "Somalia",
+            "索马里",
+            "索馬利亞",
+            "ソマリア",
+            "소말리아",
+            "Somalia",
+            "Somalie",
+            "Somalia",
+            "Сомали",
+            "Somália",
+        ],
+    ),
+    (
+        "SR",
+        [
+            "Suriname",
+            "苏里南",
+            "蘇利南",
+            "スリナム",
+            "수리남",
+            "Suriname",
+            "Suriname",
+            "Surinam",
+            "Суринам",
+            "Suriname",
+        ],
+    ),
+    (
+        "SS",
+        [
+            "South Sudan",
+            "南苏丹",
+            "南蘇丹",
+            "南スーダン",
+            "남수단",
+            "Südsudan",
+            "Soudan du Sud",
+            "Sudán del Sur",
+            "Южный Судан",
+            "Sudão do Sul",
+        ],
+    ),
+    (
+        "ST",
+        [
+            "São Tomé & Príncipe",
+            "圣多美和普林西比",
+            "聖多美普林西比",
+            "サントメ・プリンシペ",
+            "상투메 프린시페",
+            "São Tomé und Príncipe",
+            "Sao Tomé-et-Principe",
+            "Santo Tomé y Príncipe",
+            "Сан-Томе и Принсипи",
+            "São Tomé e Príncipe",
+        ],
+    ),
+    (
+        "SV",
+        [
+            "El Salvador",
+            "萨尔瓦多",
+            "薩爾瓦多",
+            "エルサルバドル",
+            "엘살바도르",
+            "El Salvador",
+            "Salvador",
+            "El Salvador",
+            "Сальвадор",
+            "El Salvador",
+        ],
+    ),
+    (
+        "SX",
+        [
+            "Sint Maarten",
+            "荷属圣马丁",
+            "荷屬聖馬丁",
+            "シント・マールテン",
+            "신트마르턴",
+            "Sint Maarten",
+            "Saint-Martin (partie néerlandaise)",
+            "Sint Maarten",
+            "Синт-Мартен",
+            "Sint Maarten",
+        ],
+    ),
+    (
+        "SY",
+        [
+            "Syria",
+            "叙利亚",
+            "敘利亞",
+            "シリア",
+            "시리아",
+            "Syrien",
+            "Syrie",
+            "Siria",
+            "Сирия",
+            "Síria",
+        ],
+    ),
+    (
+        "SZ",
+        [
+            "Eswatini",
+            "斯威士兰",
+            "史瓦帝尼",
+            "エスワティニ",
+            "에스와티니",
+            "Eswatini",
+            "Eswatini",
+            "Esuatini",
+            "Эсватини",
+            "Essuatíni",
+        ],
+    ),
+    (
+        "TC",
+        [
+            "Turks & Caicos Islands",
+            "特克斯和凯科斯群岛",
+            "土克斯及開科斯群島",
+            "タークス・カイコス諸島",
+            "터크스 케이커스 제도",
+            "Turks- und Caicosinseln",
+            "Îles Turques-et-Caïques",
+            "Islas Turcas y Caicos",
+            "Тёркс и Кайкос",
+            "Ilhas Turcas e Caicos",
+        ],
+    ),
+    (
+        "TD",
+        [
+            "Chad",
+            "乍得",
+            "查德",
+            "チャド",
+            "차드",
+            "Tschad",
+            "Tchad",
+            "Chad",
+            "Чад",
+            "Chade",
+        ],
+    ),
+    (
+        "TF",
+        [
+            "French Southern Territories",
+            "法属南部领地",
+            "法屬南部屬地",
+            "仏領極南諸島",
+            "프랑스령 남방 지역",
+            "Französische Süd- und Antarktisgebiete",
+            "Terres australes françaises",
+            "Territorios Australes Franceses",
+            "Французские Южные территории",
+            "Territórios Franceses do Sul",
+        ],
+    ),
+    (
+        "TG",
+        [
+            "Togo",
+            "多哥",
+            "多哥",
+            "トーゴ",
+            "토고",
+            "Togo",
+            "Togo",
+            "Togo",
+            "Того",
+            "Togo",
+        ],
+    ),
+    (
+        "TH",
+        [
+            "Thailand",
+            "泰国",
+            "泰國",
+            "タイ",
+            "태국",
+            "Thailand",
+            "Thaïlande",
+            "Tailandia",
+            "Таиланд",
+            "Tailândia",
+        ],
+    ),
+    (
+        "TJ",
+        [
+            "Tajikistan",
+            "塔吉克斯坦",
+            "塔吉克",
+            "タジキスタン",
+            "타지키스탄",
+            "Tadschikistan",
+            "Tadjikistan",
+            "Tayikistán",
+            "Таджикистан",
+            "Tadjiquistão",
+        ],
+    ),
+    (
+        "TK",
+        [
+            "Tokelau",
+            "托克劳",
+            "托克勞群島",
+            "トケラウ",
+            "토켈라우",
+            "Tokelau",
+            "Tokelau",
+            "Tokelau",
+            "Токелау",
+            "Tokelau",
+        ],
+    ),
+    (
+        "TL",
+        [
+            "Timor-Leste",
+            "东帝汶",
+            "東帝汶",
+            "東ティモール",
+            "동티모르",
+            "Timor-Leste",
+            "Timor oriental",
+            "Timor-Leste",
+            "Восточный Тимор",
+            "Timor-Leste",
+        ],
+    ),
+    (
+        "TM",
+        [
+            "Turkmenistan",
+            "土库曼斯坦",
+            "土庫曼",
+            "トルクメニスタン",
+            "투르크메니스탄",
+            "Turkmenistan",
+            "Turkménistan",
+            "Turkmenistán",
+            "Туркменистан",
+            "Turcomenistão",
+        ],
+    ),
+    (
+        "TN",
+        [
+            "Tunisia",
+            "突尼斯",
+            "突尼西亞",
+            "チュニジア",
+            "튀니지",
+            "Tunesien",
+            "Tunisie",
+            "Túnez",
+            "Тунис",
+            "Tunísia",
+        ],
+    ),
+    (
+        "TO",
+        [
+            "Tonga",
+            "汤加",
+            "東加",
+            "トンガ",
+            "통가",
+            "Tonga",
+            "Tonga",
+            "Tonga",
+            "Тонга",
+            "Tonga",
+        ],
+    ),
+    (
+        "TR",
+        [
+            "Türkiye",
+            "土耳其",
+            "土耳其",
+            "トルコ",
+            "튀르키예",
+            "Türkei",
+            "Turquie",
+            "Turquía",
+            "Турция",
+            "Turquia",
+        ],
+    ),
+    (
+        "TT",
+        [
+            "Trinidad & Tobago",
+            "特立尼达和多巴哥",
+            "千里達及托巴哥",
+            "トリニダード・トバゴ",
+            "트리니다드 토바고",
+            "Trinidad und Tobago",
+            "Trinité-et-Tobago",
+            "Trinidad y Tobago",
+            "Тринидад и Тобаго",
+            "Trinidad e Tobago",
+        ],
+    ),
+    (
+        "TV",
+        [
+            "Tuvalu",
+            "图瓦卢",
+            "吐瓦魯",
+            "ツバル",
+            "투발루",
+            "Tuvalu",
+            "Tuvalu",
+            "Tuvalu",
+            "Тувалу",
+            "Tuvalu",
+        ],
+    ),
+    (
+        "TW",
+        [
+            "Taiwan",
+            "台湾",
+            "台灣",
+            "台湾",
+            "대만",
+            "Taiwan",
+            "Taïwan",
+            "Taiwán",
+            "Тайвань",
+            "Taiwan",
+        ],
+    ),
+    (
+        "TZ",
+        [
+            "Tanzania",
+            "坦桑尼亚",
+            "坦尚尼亞",
+            "タンザニア",
+            "탄자니아",
+            "Tansania",
+            "Tanzanie",
+            "Tanzania",
+            "Танзания",
+            "Tanzânia",
+        ],
+    ),
+    (
+        "UA",
+        [
+            "Ukraine",
+            "乌克兰",
+            "烏克蘭",
+            "ウクライナ",
+            "우크라이나",
+            "Ukraine",
+            "Ukraine",
+            "Ucrania",
+            "Украина",
+            "Ucrânia",
+        ],
+    ),
+    (
+        "UG",
+        [
+            "Uganda",
+            "乌干达",
+            "烏干達",
+            "ウガンダ",
+            "우간다",
+            "Uganda",
+            "Ouganda",
+            "Uganda",
+            "Уганда",
+            "Uganda",
+        ],
+    ),
+    (
+        "UM",
+        [
+            "U.S. Outlying Islands",
+            "美国本土外小岛屿",
+            "美國本土外小島嶼",
+            "合衆国領有小離島",
+            "미국령 해외 제도",
+            "Amerikanische Überseeinseln",
+            "Îles mineures éloignées des États-Unis",
+            "Islas menores alejadas de EE. UU.",
+            "Внешние малые о-ва (США)",
+            "Ilhas Menores Distantes dos EUA",
+        ],
+    ),
+    (
+        "US",
+        [
+            "United States",
+            "美国",
+            "美國",
+            "アメリカ合衆国",
+            "미국",
+            "Vereinigte Staaten",
+            "États-Unis",
+            "Estados Unidos",
+            "Соединенные Штаты",
+            "Estados Unidos",
+        ],
+    ),
+    (
+        "UY",
+        [
+            "Uruguay",
+            "乌拉圭",
+            "烏拉圭",
+            "ウルグアイ",
+            "우루과이",
+            "Uruguay",
+            "Uruguay",
+            "Uruguay",
+            "Уругвай",
+            "Uruguai",
+        ],
+    ),
+    (
+        "UZ",
+        [
+            "Uzbekistan",
+            "乌兹别克斯坦",
+            "烏茲別克",
+            "ウズベキスタン",
+            "우즈베키스탄",
+            "Usbekistan",
+            "Ouzbékistan",
+            "Uzbekistán",
+            "Узбекистан",
+            "Uzbequistão",
+        ],
+    ),
+    (
+        "VA",
+        [
+            "Vatican City",
+            "梵蒂冈",
+            "梵蒂岡",
+            "バチカン市国",
+            "바티칸 시국",
+            "Vatikanstadt",
+            "État de la Cité du Vatican",
+            "Ciudad del Vaticano",
+            "Ватикан",
+            "Cidade do Vaticano",
+        ],
+    ),
+    (
+        "VC",
+        [
+            "St. Vincent & Grenadines",
+            "圣文森特和格林纳丁斯",
+            "聖文森及格瑞那丁",
+            "セントビンセント及びグレナディーン諸島",
+            "세인트빈센트그레나딘",
+            "St. Vincent und die Grenadinen",
+            "Saint-Vincent-et-les Grenadines",
+            "San Vicente y las Granadinas",
+            "Сент-Винсент и Гренадины",
+            "São Vicente e Granadinas",
+        ],
+    ),
+    (
+        "VE",
+        [
+            "Venezuela",
+            "委内瑞拉",
+            "委內瑞拉",
+            "ベネズエラ",
+            "베네수엘라",
+            "Venezuela",
+            "Venezuela",
+            "Venezuela",
+            "Венесуэла",
+            "Venezuela",
+        ],
+    ),
+    (
+        "VG",
+        [
+            "British Virgin Islands",
+            "英属维尔京群岛",
+            "英屬維京群島",
+            "英領ヴァージン諸島",
+            "영국령 버진아일랜드",
+            "Britische Jungferninseln",
+            "Îles Vierges britanniques",
+            "Islas Vírgenes Británicas",
+            "Виргинские о-ва (Великобритания)",
+            "Ilhas Virgens Britânicas",
+        ],
+    ),
+    (
+        "VI",
+        [
+            "U.S. Virgin Islands",
+            "美属维尔京群岛",
+            "美屬維京群島",
+            "米領ヴァージン諸島",
+            "미국령 버진아일랜드",
+            "Amerikanische Jungferninseln",
+            "Îles Vierges des États-Unis",
+            "Islas Vírgenes de EE. UU.",
+            "Виргинские о-ва (США)",
+            "Ilhas Virgens Americanas",
+        ],
+    ),
+    (
+        "VN",
+        [
+            "Vietnam",
+            "越南",
+            "越南",
+            "ベトナム",
+            "베트남",
+            "Vietnam",
+            "Viêt Nam",
+            "Vietnam",
+            "Вьетнам",
+            "Vietnã",
+        ],
+    ),
+    (
+        "VU",
+        [
+            "Vanuatu",
+            "瓦努阿图",
+            "萬那杜",
+            "バヌアツ",
+            "바누아투",
+            "Vanuatu",
+            "Vanuatu",
+            "Vanuatu",
+            "Вануату",
+            "Vanuatu",
+        ],
+    ),
+    (
+        "WF",
+        [
+            "Wallis & Futuna",
+            "瓦利斯和富图纳",
+            "瓦利斯群島和富圖那群島",
+            "ウォリス・フツナ",
+            "왈리스-푸투나 제도",
+            "Wallis und Futuna",
+            "Wallis-et-Futuna",
+            "Wallis y Futuna",
+            "Уоллис и Футуна",
+            "Wallis e Futuna",
+        ],
+    ),
+    (
+        "WS",
+        [
+            "Samoa",
+            "萨摩亚",
+            "薩摩亞",
+            "サモア",
+            "사모아",
+            "Samoa",
+            "Samoa",
+            "Samoa",
+            "Самоа",
+            "Samoa",
+        ],
+    ),
+    (
+        "XK",
+        [
+            "Kosovo",
+            "科索沃",
+            "科索沃",
+            "コソボ",
+            "코소보",
+            "Kosovo",
+            "Kosovo",
+            "Kosovo",
+            "Косово",
+            "Kosovo",
+        ],
+    ),
+    (
+        "YE",
+        [
+            "Yemen",
+            "也门",
+            "葉門",
+            "イエメン",
+            "예멘",
+            "Jemen",
+            "Yémen",
+            "Yemen",
+            "Йемен",
+            "Iêmen",
+        ],
+    ),
+    (
+        "YT",
+        [
+            "Mayotte",
+            "马约特",
+            "馬約特島",
+            "マヨット",
+            "마요트",
+            "Mayotte",
+            "Mayotte",
+            "Mayotte",
+            "Майотта",
+            "Mayotte",
+        ],
+    ),
+    (
+        "ZA",
+        [
+            "South Africa",
+            "南非",
+            "南非",
+            "南アフリカ",
+            "남아프리카",
+            "Südafrika",
+            "Afrique du Sud",
+            "Sudáfrica",
+            "Южно-Африканская Республика",
+            "África do Sul",
+        ],
+    ),
+    (
+        "ZM",
+        [
+            "Zambia",
+            "赞比亚",
+            "尚比亞",
+            "ザンビア",
+            "잠비아",
+            "Sambia",
+            "Zambie",
+            "Zambia",
+            "Замбия",
+            "Zâmbia",
+        ],
+    ),
+    (
+        "ZW",
+        [
+            "Zimbabwe",
+            "津巴布韦",
+            "辛巴威",
+            "ジンバブエ",
+            "짐바브웨",
+            "Simbabwe",
+            "Zimbabwe",
+            "Zimbabue",
+            "Зимбабве",
+            "Zimbábue",
+        ],
+    ),
+];
+
+// 国家(地区)的扩展信息
+pub struct CountryInfo {
+    // 大洲代码，如AS, EU
+    pub continent: &'static str,
+    // 联合国M49的区域与子区域代码
+    pub region: &'static str,
+    pub subregion: &'static str,
+    // 是否欧盟以及欧洲经济区成员(包括欧盟的海外领土)
+    pub eu: bool,
+    pub eea: bool,
+    // 国际电话区号
+    pub calling_code: &'static str,
+    // 货币代码(ISO 4217)
+    pub currency: &'static str,
+}
+
+// 按国家代码排序，大洲与区域数据来源于联合国M49，电话区号来源于libphonenumber
+pub static COUNTRY_INFOS: [(&str, CountryInfo); 250] = [
+    (
+        "AD",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+376",
+            currency: "EUR",
+        },
+    ),
+    (
+        "AE",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+971",
+            currency: "AED",
+        },
+    ),
+    (
+        "AF",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+93",
+            currency: "AFN",
+        },
+    ),
+    (
+        "AG",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "AI",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "AL",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+355",
+            currency: "ALL",
+        },
+    ),
+    (
+        "AM",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+374",
+            currency: "AMD",
+        },
+    ),
+    (
+        "AO",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+244",
+            currency: "AOA",
+        },
+    ),
+    (
+        "AQ",
+        CountryInfo {
+            continent: "AN",
+            region: "",
+            subregion: "",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "",
+        },
+    ),
+    (
+        "AR",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+54",
+            currency: "ARS",
+        },
+    ),
+    (
+        "AS",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "AT",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+43",
+            currency: "EUR",
+        },
+    ),
+    (
+        "AU",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "+61",
+            currency: "AUD",
+        },
+    ),
+    (
+        "AW",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+297",
+            currency: "AWG",
+        },
+    ),
+    (
+        "AX",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+358",
+            currency: "EUR",
+        },
+    ),
+    (
+        "AZ",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+994",
+            currency: "AZN",
+        },
+    ),
+    (
+        "BA",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+387",
+            currency: "BAM",
+        },
+    ),
+    (
+        "BB",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "BBD",
+        },
+    ),
+    (
+        "BD",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+880",
+            currency: "BDT",
+        },
+    ),
+    (
+        "BE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+32",
+            currency: "EUR",
+        },
+    ),
+    (
+        "BF",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+226",
+            currency: "XOF",
+        },
+    ),
+    (
+        "BG",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+359",
+            currency: "EUR",
+        },
+    ),
+    (
+        "BH",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+973",
+            currency: "BHD",
+        },
+    ),
+    (
+        "BI",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+257",
+            currency: "BIF",
+        },
+    ),
+    (
+        "BJ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+229",
+            currency: "XOF",
+        },
+    ),
+    (
+        "BL",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+590",
+            currency: "EUR",
+        },
+    ),
+    (
+        "BM",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "021",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "BMD",
+        },
+    ),
+    (
+        "BN",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+673",
+            currency: "BND",
+        },
+    ),
+    (
+        "BO",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+591",
+            currency: "BOB",
+        },
+    ),
+    (
+        "BQ",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+599",
+            currency: "USD",
+        },
+    ),
+    (
+        "BR",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+55",
+            currency: "BRL",
+        },
+    ),
+    (
+        "BS",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "BSD",
+        },
+    ),
+    (
+        "BT",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+975",
+            currency: "BTN",
+        },
+    ),
+    (
+        "BV",
+        CountryInfo {
+            continent: "AN",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "NOK",
+        },
+    ),
+    (
+        "BW",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+267",
+            currency: "BWP",
+        },
+    ),
+    (
+        "BY",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: false,
+            eea: false,
+            calling_code: "+375",
+            currency: "BYN",
+        },
+    ),
+    (
+        "BZ",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+501",
+            currency: "BZD",
+        },
+    ),
+    (
+        "CA",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "021",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "CAD",
+        },
+    ),
+    (
+        "CC",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "+61",
+            currency: "AUD",
+        },
+    ),
+    (
+        "CD",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+243",
+            currency: "CDF",
+        },
+    ),
+    (
+        "CF",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+236",
+            currency: "XAF",
+        },
+    ),
+    (
+        "CG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+242",
+            currency: "XAF",
+        },
+    ),
+    (
+        "CH",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: false,
+            eea: false,
+            calling_code: "+41",
+            currency: "CHF",
+        },
+    ),
+    (
+        "CI",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+225",
+            currency: "XOF",
+        },
+    ),
+    (
+        "CK",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+682",
+            currency: "NZD",
+        },
+    ),
+    (
+        "CL",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+56",
+            currency: "CLP",
+        },
+    ),
+    (
+        "CM",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+237",
+            currency: "XAF",
+        },
+    ),
+    (
+        "CN",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+86",
+            currency: "CNY",
+        },
+    ),
+    (
+        "CO",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+57",
+            currency: "COP",
+        },
+    ),
+    (
+        "CR",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+506",
+            currency: "CRC",
+        },
+    ),
+    (
+        "CU",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+53",
+            currency: "CUP",
+        },
+    ),
+    (
+        "CV",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+238",
+            currency: "CVE",
+        },
+    ),
+    (
+        "CW",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+599",
+            currency: "XCG",
+        },
+    ),
+    (
+        "CX",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "+61",
+            currency: "AUD",
+        },
+    ),
+    (
+        "CY",
+        CountryInfo {
+            continent: "EU",
+            region: "142",
+            subregion: "145",
+            eu: true,
+            eea: true,
+            calling_code: "+357",
+            currency: "EUR",
+        },
+    ),
+    (
+        "CZ",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+420",
+            currency: "CZK",
+        },
+    ),
+    (
+        "DE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+49",
+            currency: "EUR",
+        },
+    ),
+    (
+        "DJ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+253",
+            currency: "DJF",
+        },
+    ),
+    (
+        "DK",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+45",
+            currency: "DKK",
+        },
+    ),
+    (
+        "DM",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "DO",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "DOP",
+        },
+    ),
+    (
+        "DZ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+213",
+            currency: "DZD",
+        },
+    ),
+    (
+        "EC",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+593",
+            currency: "USD",
+        },
+    ),
+    (
+        "EE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+372",
+            currency: "EUR",
+        },
+    ),
+    (
+        "EG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+20",
+            currency: "EGP",
+        },
+    ),
+    (
+        "EH",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+212",
+            currency: "MAD",
+        },
+    ),
+    (
+        "ER",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+291",
+            currency: "ERN",
+        },
+    ),
+    (
+        "ES",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+34",
+            currency: "EUR",
+        },
+    ),
+    (
+        "ET",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+251",
+            currency: "ETB",
+        },
+    ),
+    (
+        "FI",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+358",
+            currency: "EUR",
+        },
+    ),
+    (
+        "FJ",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "054",
+            eu: false,
+            eea: false,
+            calling_code: "+679",
+            currency: "FJD",
+        },
+    ),
+    (
+        "FK",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+500",
+            currency: "FKP",
+        },
+    ),
+    (
+        "FM",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+691",
+            currency: "USD",
+        },
+    ),
+    (
+        "FO",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+298",
+            currency: "DKK",
+        },
+    ),
+    (
+        "FR",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+33",
+            currency: "EUR",
+        },
+    ),
+    (
+        "GA",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+241",
+            currency: "XAF",
+        },
+    ),
+    (
+        "GB",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+44",
+            currency: "GBP",
+        },
+    ),
+    (
+        "GD",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "GE",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+995",
+            currency: "GEL",
+        },
+    ),
+    (
+        "GF",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: true,
+            eea: true,
+            calling_code: "+594",
+            currency: "EUR",
+        },
+    ),
+    (
+        "GG",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+44",
+            currency: "GBP",
+        },
+    ),
+    (
+        "GH",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+233",
+            currency: "GHS",
+        },
+    ),
+    (
+        "GI",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+350",
+            currency: "GIP",
+        },
+    ),
+    (
+        "GL",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "021",
+            eu: false,
+            eea: false,
+            calling_code: "+299",
+            currency: "DKK",
+        },
+    ),
+    (
+        "GM",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+220",
+            currency: "GMD",
+        },
+    ),
+    (
+        "GN",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+224",
+            currency: "GNF",
+        },
+    ),
+    (
+        "GP",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: true,
+            eea: true,
+            calling_code: "+590",
+            currency: "EUR",
+        },
+    ),
+    (
+        "GQ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+240",
+            currency: "XAF",
+        },
+    ),
+    (
+        "GR",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+30",
+            currency: "EUR",
+        },
+    ),
+    (
+        "GS",
+        CountryInfo {
+            continent: "AN",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "GBP",
+        },
+    ),
+    (
+        "GT",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+502",
+            currency: "GTQ",
+        },
+    ),
+    (
+        "GU",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "GW",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+245",
+            currency: "XOF",
+        },
+    ),
+    (
+        "GY",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+592",
+            currency: "GYD",
+        },
+    ),
+    (
+        "HK",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+852",
+            currency: "HKD",
+        },
+    ),
+    (
+        "HM",
+        CountryInfo {
+            continent: "AN",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "AUD",
+        },
+    ),
+    (
+        "HN",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+504",
+            currency: "HNL",
+        },
+    ),
+    (
+        "HR",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+385",
+            currency: "EUR",
+        },
+    ),
+    (
+        "HT",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+509",
+            currency: "HTG",
+        },
+    ),
+    (
+        "HU",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+36",
+            currency: "HUF",
+        },
+    ),
+    (
+        "ID",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+62",
+            currency: "IDR",
+        },
+    ),
+    (
+        "IE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+353",
+            currency: "EUR",
+        },
+    ),
+    (
+        "IL",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+972",
+            currency: "ILS",
+        },
+    ),
+    (
+        "IM",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+44",
+            currency: "GBP",
+        },
+    ),
+    (
+        "IN",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+91",
+            currency: "INR",
+        },
+    ),
+    (
+        "IO",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+246",
+            currency: "USD",
+        },
+    ),
+    (
+        "IQ",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+964",
+            currency: "IQD",
+        },
+    ),
+    (
+        "IR",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+98",
+            currency: "IRR",
+        },
+    ),
+    (
+        "IS",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: true,
+            calling_code: "+354",
+            currency: "ISK",
+        },
+    ),
+    (
+        "IT",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+39",
+            currency: "EUR",
+        },
+    ),
+    (
+        "JE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+44",
+            currency: "GBP",
+        },
+    ),
+    (
+        "JM",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "JMD",
+        },
+    ),
+    (
+        "JO",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+962",
+            currency: "JOD",
+        },
+    ),
+    (
+        "JP",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+81",
+            currency: "JPY",
+        },
+    ),
+    (
+        "KE",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+254",
+            currency: "KES",
+        },
+    ),
+    (
+        "KG",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "143",
+            eu: false,
+            eea: false,
+            calling_code: "+996",
+            currency: "KGS",
+        },
+    ),
+    (
+        "KH",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+855",
+            currency: "KHR",
+        },
+    ),
+    (
+        "KI",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+686",
+            currency: "AUD",
+        },
+    ),
+    (
+        "KM",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+269",
+            currency: "KMF",
+        },
+    ),
+    (
+        "KN",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "KP",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+850",
+            currency: "KPW",
+        },
+    ),
+    (
+        "KR",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+82",
+            currency: "KRW",
+        },
+    ),
+    (
+        "KW",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+965",
+            currency: "KWD",
+        },
+    ),
+    (
+        "KY",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "KYD",
+        },
+    ),
+    (
+        "KZ",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "143",
+            eu: false,
+            eea: false,
+            calling_code: "+7",
+            currency: "KZT",
+        },
+    ),
+    (
+        "LA",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+856",
+            currency: "LAK",
+        },
+    ),
+    (
+        "LB",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+961",
+            currency: "LBP",
+        },
+    ),
+    (
+        "LC",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "LI",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: false,
+            eea: true,
+            calling_code: "+423",
+            currency: "CHF",
+        },
+    ),
+    (
+        "LK",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+94",
+            currency: "LKR",
+        },
+    ),
+    (
+        "LR",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+231",
+            currency: "LRD",
+        },
+    ),
+    (
+        "LS",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+266",
+            currency: "LSL",
+        },
+    ),
+    (
+        "LT",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+370",
+            currency: "EUR",
+        },
+    ),
+    (
+        "LU",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+352",
+            currency: "EUR",
+        },
+    ),
+    (
+        "LV",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+371",
+            currency: "EUR",
+        },
+    ),
+    (
+        "LY",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+218",
+            currency: "LYD",
+        },
+    ),
+    (
+        "MA",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+212",
+            currency: "MAD",
+        },
+    ),
+    (
+        "MC",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: false,
+            eea: false,
+            calling_code: "+377",
+            currency: "EUR",
+        },
+    ),
+    (
+        "MD",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: false,
+            eea: false,
+            calling_code: "+373",
+            currency: "MDL",
+        },
+    ),
+    (
+        "ME",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+382",
+            currency: "EUR",
+        },
+    ),
+    (
+        "MF",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: true,
+            eea: true,
+            calling_code: "+590",
+            currency: "EUR",
+        },
+    ),
+    (
+        "MG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+261",
+            currency: "MGA",
+        },
+    ),
+    (
+        "MH",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+692",
+            currency: "USD",
+        },
+    ),
+    (
+        "MK",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+389",
+            currency: "MKD",
+        },
+    ),
+    (
+        "ML",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+223",
+            currency: "XOF",
+        },
+    ),
+    (
+        "MM",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+95",
+            currency: "MMK",
+        },
+    ),
+    (
+        "MN",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+976",
+            currency: "MNT",
+        },
+    ),
+    (
+        "MO",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+853",
+            currency: "MOP",
+        },
+    ),
+    (
+        "MP",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "MQ",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: true,
+            eea: true,
+            calling_code: "+596",
+            currency: "EUR",
+        },
+    ),
+    (
+        "MR",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+222",
+            currency: "MRU",
+        },
+    ),
+    (
+        "MS",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "MT",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+356",
+            currency: "EUR",
+        },
+    ),
+    (
+        "MU",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+230",
+            currency: "MUR",
+        },
+    ),
+    (
+        "MV",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+960",
+            currency: "MVR",
+        },
+    ),
+    (
+        "MW",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+265",
+            currency: "MWK",
+        },
+    ),
+    (
+        "MX",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+52",
+            currency: "MXN",
+        },
+    ),
+    (
+        "MY",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+60",
+            currency: "MYR",
+        },
+    ),
+    (
+        "MZ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+258",
+            currency: "MZN",
+        },
+    ),
+    (
+        "NA",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+264",
+            currency: "NAD",
+        },
+    ),
+    (
+        "NC",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "054",
+            eu: false,
+            eea: false,
+            calling_code: "+687",
+            currency: "XPF",
+        },
+    ),
+    (
+        "NE",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+227",
+            currency: "XOF",
+        },
+    ),
+    (
+        "NF",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "+672",
+            currency: "AUD",
+        },
+    ),
+    (
+        "NG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+234",
+            currency: "NGN",
+        },
+    ),
+    (
+        "NI",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+505",
+            currency: "NIO",
+        },
+    ),
+    (
+        "NL",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "155",
+            eu: true,
+            eea: true,
+            calling_code: "+31",
+            currency: "EUR",
+        },
+    ),
+    (
+        "NO",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: true,
+            calling_code: "+47",
+            currency: "NOK",
+        },
+    ),
+    (
+        "NP",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+977",
+            currency: "NPR",
+        },
+    ),
+    (
+        "NR",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+674",
+            currency: "AUD",
+        },
+    ),
+    (
+        "NU",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+683",
+            currency: "NZD",
+        },
+    ),
+    (
+        "NZ",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "053",
+            eu: false,
+            eea: false,
+            calling_code: "+64",
+            currency: "NZD",
+        },
+    ),
+    (
+        "OM",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+968",
+            currency: "OMR",
+        },
+    ),
+    (
+        "PA",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+507",
+            currency: "PAB",
+        },
+    ),
+    (
+        "PE",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+51",
+            currency: "PEN",
+        },
+    ),
+    (
+        "PF",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+689",
+            currency: "XPF",
+        },
+    ),
+    (
+        "PG",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "054",
+            eu: false,
+            eea: false,
+            calling_code: "+675",
+            currency: "PGK",
+        },
+    ),
+    (
+        "PH",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+63",
+            currency: "PHP",
+        },
+    ),
+    (
+        "PK",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "034",
+            eu: false,
+            eea: false,
+            calling_code: "+92",
+            currency: "PKR",
+        },
+    ),
+    (
+        "PL",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+48",
+            currency: "PLN",
+        },
+    ),
+    (
+        "PM",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "021",
+            eu: false,
+            eea: false,
+            calling_code: "+508",
+            currency: "EUR",
+        },
+    ),
+    (
+        "PN",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "NZD",
+        },
+    ),
+    (
+        "PR",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "PS",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+970",
+            currency: "ILS",
+        },
+    ),
+    (
+        "PT",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+351",
+            currency: "EUR",
+        },
+    ),
+    (
+        "PW",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "+680",
+            currency: "USD",
+        },
+    ),
+    (
+        "PY",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+595",
+            currency: "PYG",
+        },
+    ),
+    (
+        "QA",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+974",
+            currency: "QAR",
+        },
+    ),
+    (
+        "RE",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: true,
+            eea: true,
+            calling_code: "+262",
+            currency: "EUR",
+        },
+    ),
+    (
+        "RO",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+40",
+            currency: "RON",
+        },
+    ),
+    (
+        "RS",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+381",
+            currency: "RSD",
+        },
+    ),
+    (
+        "RU",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: false,
+            eea: false,
+            calling_code: "+7",
+            currency: "RUB",
+        },
+    ),
+    (
+        "RW",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+250",
+            currency: "RWF",
+        },
+    ),
+    (
+        "SA",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+966",
+            currency: "SAR",
+        },
+    ),
+    (
+        "SB",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "054",
+            eu: false,
+            eea: false,
+            calling_code: "+677",
+            currency: "SBD",
+        },
+    ),
+    (
+        "SC",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+248",
+            currency: "SCR",
+        },
+    ),
+    (
+        "SD",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+249",
+            currency: "SDG",
+        },
+    ),
+    (
+        "SE",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: true,
+            eea: true,
+            calling_code: "+46",
+            currency: "SEK",
+        },
+    ),
+    (
+        "SG",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+65",
+            currency: "SGD",
+        },
+    ),
+    (
+        "SH",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+290",
+            currency: "SHP",
+        },
+    ),
+    (
+        "SI",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: true,
+            eea: true,
+            calling_code: "+386",
+            currency: "EUR",
+        },
+    ),
+    (
+        "SJ",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "154",
+            eu: false,
+            eea: false,
+            calling_code: "+47",
+            currency: "NOK",
+        },
+    ),
+    (
+        "SK",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: true,
+            eea: true,
+            calling_code: "+421",
+            currency: "EUR",
+        },
+    ),
+    (
+        "SL",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+232",
+            currency: "SLE",
+        },
+    ),
+    (
+        "SM",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+378",
+            currency: "EUR",
+        },
+    ),
+    (
+        "SN",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+221",
+            currency: "XOF",
+        },
+    ),
+    (
+        "SO",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+252",
+            currency: "SOS",
+        },
+    ),
+    (
+        "SR",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+597",
+            currency: "SRD",
+        },
+    ),
+    (
+        "SS",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+211",
+            currency: "SSP",
+        },
+    ),
+    (
+        "ST",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+239",
+            currency: "STN",
+        },
+    ),
+    (
+        "SV",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+503",
+            currency: "USD",
+        },
+    ),
+    (
+        "SX",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCG",
+        },
+    ),
+    (
+        "SY",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+963",
+            currency: "SYP",
+        },
+    ),
+    (
+        "SZ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+268",
+            currency: "SZL",
+        },
+    ),
+    (
+        "TC",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "TD",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+235",
+            currency: "XAF",
+        },
+    ),
+    (
+        "TF",
+        CountryInfo {
+            continent: "AN",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "EUR",
+        },
+    ),
+    (
+        "TG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+228",
+            currency: "XOF",
+        },
+    ),
+    (
+        "TH",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+66",
+            currency: "THB",
+        },
+    ),
+    (
+        "TJ",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "143",
+            eu: false,
+            eea: false,
+            calling_code: "+992",
+            currency: "TJS",
+        },
+    ),
+    (
+        "TK",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+690",
+            currency: "NZD",
+        },
+    ),
+    (
+        "TL",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+670",
+            currency: "USD",
+        },
+    ),
+    (
+        "TM",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "143",
+            eu: false,
+            eea: false,
+            calling_code: "+993",
+            currency: "TMT",
+        },
+    ),
+    (
+        "TN",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "015",
+            eu: false,
+            eea: false,
+            calling_code: "+216",
+            currency: "TND",
+        },
+    ),
+    (
+        "TO",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+676",
+            currency: "TOP",
+        },
+    ),
+    (
+        "TR",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+90",
+            currency: "TRY",
+        },
+    ),
+    (
+        "TT",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "TTD",
+        },
+    ),
+    (
+        "TV",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+688",
+            currency: "AUD",
+        },
+    ),
+    (
+        "TW",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "030",
+            eu: false,
+            eea: false,
+            calling_code: "+886",
+            currency: "TWD",
+        },
+    ),
+    (
+        "TZ",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+255",
+            currency: "TZS",
+        },
+    ),
+    (
+        "UA",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "151",
+            eu: false,
+            eea: false,
+            calling_code: "+380",
+            currency: "UAH",
+        },
+    ),
+    (
+        "UG",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+256",
+            currency: "UGX",
+        },
+    ),
+    (
+        "UM",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "057",
+            eu: false,
+            eea: false,
+            calling_code: "",
+            currency: "USD",
+        },
+    ),
+    (
+        "US",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "021",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "UY",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+598",
+            currency: "UYU",
+        },
+    ),
+    (
+        "UZ",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "143",
+            eu: false,
+            eea: false,
+            calling_code: "+998",
+            currency: "UZS",
+        },
+    ),
+    (
+        "VA",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+39",
+            currency: "EUR",
+        },
+    ),
+    (
+        "VC",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "XCD",
+        },
+    ),
+    (
+        "VE",
+        CountryInfo {
+            continent: "SA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+58",
+            currency: "VES",
+        },
+    ),
+    (
+        "VG",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "VI",
+        CountryInfo {
+            continent: "NA",
+            region: "019",
+            subregion: "419",
+            eu: false,
+            eea: false,
+            calling_code: "+1",
+            currency: "USD",
+        },
+    ),
+    (
+        "VN",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "035",
+            eu: false,
+            eea: false,
+            calling_code: "+84",
+            currency: "VND",
+        },
+    ),
+    (
+        "VU",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "054",
+            eu: false,
+            eea: false,
+            calling_code: "+678",
+            currency: "VUV",
+        },
+    ),
+    (
+        "WF",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+681",
+            currency: "XPF",
+        },
+    ),
+    (
+        "WS",
+        CountryInfo {
+            continent: "OC",
+            region: "009",
+            subregion: "061",
+            eu: false,
+            eea: false,
+            calling_code: "+685",
+            currency: "WST",
+        },
+    ),
+    (
+        "XK",
+        CountryInfo {
+            continent: "EU",
+            region: "150",
+            subregion: "039",
+            eu: false,
+            eea: false,
+            calling_code: "+383",
+            currency: "EUR",
+        },
+    ),
+    (
+        "YE",
+        CountryInfo {
+            continent: "AS",
+            region: "142",
+            subregion: "145",
+            eu: false,
+            eea: false,
+            calling_code: "+967",
+            currency: "YER",
+        },
+    ),
+    (
+        "YT",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: true,
+            eea: true,
+            calling_code: "+262",
+            currency: "EUR",
+        },
+    ),
+    (
+        "ZA",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+27",
+            currency: "ZAR",
+        },
+    ),
+    (
+        "ZM",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+260",
+            currency: "ZMW",
+        },
+    ),
+    (
+        "ZW",
+        CountryInfo {
+            continent: "AF",
+            region: "002",
+            subregion: "202",
+            eu: false,
+            eea: false,
+            calling_code: "+263",
+            currency: "ZWG",
+        },
+    ),
+];
+
+// 大洲的名称，数据来源于CLDR
+pub static CONTINENT_NAMES: [(&str, [&str; 10]); 7] = [
+    (
+        "AF",
+        [
+            "Africa",
+            "非洲",
+            "非洲",
+            "アフリカ",
+            "아프리카",
+            "Afrika",
+            "Afrique",
+            "África",
+            "Африка",
+            "África",
+        ],
+    ),
+    (
+        "AN",
+        [
+            "Antarctica",
+            "南极洲",
+            "南極洲",
+            "南極",
+            "남극 대륙",
+            "Antarktis",
+            "Antarctique",
+            "Antártida",
+            "Антарктида",
+            "Antártida",
+        ],
+    ),
+    (
+        "AS",
+        [
+            "Asia",
+            "亚洲",
+            "亞洲",
+            "アジア",
+            "아시아",
+            "Asien",
+            "Asie",
+            "Asia",
+            "Азия",
+            "Ásia",
+        ],
+    ),
+    (
+        "EU",
+        [
+            "Europe",
+            "欧洲",
+            "歐洲",
+            "ヨーロッパ",
+            "유럽",
+            "Europa",
+            "Europe",
+            "Europa",
+            "Европа",
+            "Europa",
+        ],
+    ),
+    (
+        "NA",
+        [
+            "North America",
+            "北美洲",
+            "北美洲",
+            "北アメリカ大陸",
+            "북아메리카",
+            "Nordamerika",
+            "Amérique du Nord",
+            "América del Norte",
+            "Северная Америка",
+            "América do Norte",
+        ],
+    ),
+    (
+        "OC",
+        [
+            "Oceania",
+            "大洋洲",
+            "大洋洲",
+            "オセアニア",
+            "오세아니아",
+            "Ozeanien",
+            "Océanie",
+            "Oceanía",
+            "Океания",
+            "Oceania",
+        ],
+    ),
+    (
+        "SA",
+        [
+            "South America",
+            "南美洲",
+            "南美洲",
+            "南アメリカ",
+            "남아메리카",
+            "Südamerika",
+            "Amérique du Sud",
+            "Sudamérica",
+            "Южная Америка",
+            "América do Sul",
+        ],
+    ),
+];
+
+// 联合国M49区域的名称，数据来源于CLDR
+pub static REGION_NAMES: [(&str, [&str; 10]); 22] = [
+    (
+        "002",
+        [
+            "Africa",
+            "非洲",
+            "非洲",
+            "アフリカ",
+            "아프리카",
+            "Afrika",
+            "Afrique",
+            "África",
+            "Африка",
+            "África",
+        ],
+    ),
+    (
+        "009",
+        [
+            "Oceania",
+            "大洋洲",
+            "大洋洲",
+            "オセアニア",
+            "오세아니아",
+            "Ozeanien",
+            "Océanie",
+            "Oceanía",
+            "Океания",
+            "Oceania",
+        ],
+    ),
+    (
+        "015",
+        [
+            "Northern Africa",
+            "北非",
+            "北非",
+            "北アフリカ",
+            "북부 아프리카",
+            "Nordafrika",
+            "Afrique septentrionale",
+            "África septentrional",
+            "Северная Африка",
+            "África Setentrional",
+        ],
+    ),
+    (
+        "019",
+        [
+            "Americas",
+            "美洲",
+            "美洲",
+            "アメリカ大陸",
+            "아메리카 대륙",
+            "Amerika",
+            "Amériques",
+            "América",
+            "Америка",
+            "Américas",
+        ],
+    ),
+    (
+        "021",
+        [
+            "Northern America",
+            "美洲北部",
+            "北美",
+            "北アメリカ",
+            "북부 아메리카",
+            "Nördliches Amerika",
+            "Amérique septentrionale",
+            "Norteamérica",
+            "Североамериканский регион",
+            "América Setentrional",
+        ],
+    ),
+    (
+        "030",
+        [
+            "Eastern Asia",
+            "东亚",
+            "東亞",
+            "東アジア",
+            "동아시아",
+            "Ostasien",
+            "Asie de l’Est",
+            "Asia oriental",
+            "Восточная Азия",
+            "Ásia Oriental",
+        ],
+    ),
+    (
+        "034",
+        [
+            "Southern Asia",
+            "南亚",
+            "南亞",
+            "南アジア",
+            "남아시아",
+            "Südasien",
+            "Asie du Sud",
+            "Asia meridional",
+            "Южная Азия",
+            "Ásia Meridional",
+        ],
+    ),
+    (
+        "035",
+        [
+            "Southeast Asia",
+            "东南亚",
+            "東南亞",
+            "東南アジア",
+            "동남아시아",
+            "Südostasien",
+            "Asie du Sud-Est",
+            "Sudeste asiático",
+            "Юго-Восточная Азия",
+            "Sudeste Asiático",
+        ],
+    ),
+    (
+        "039",
+        [
+            "Southern Europe",
+            "南欧",
+            "南歐",
+            "南ヨーロッパ",
+            "남유럽",
+            "Südeuropa",
+            "Europe du Sud",
+            "Europa meridional",
+            "Южная Европа",
+            "Europa Meridional",
+        ],
+    ),
+    (
+        "053",
+        [
+            "Australasia",
+            "澳大拉西亚",
+            "澳洲與紐西蘭",
+            "オーストララシア",
+            "오스트랄라시아",
+            "Australasien",
+            "Australasie",
+            "Australasia",
+            "Австралазия",
+            "Australásia",
+        ],
+    ),
+    (
+        "054",
+        [
+            "Melanesia",
+            "美拉尼西亚",
+            "美拉尼西亞",
+            "メラネシア",
+            "멜라네시아",
+            "Melanesien",
+            "Mélanésie",
+            "Melanesia",
+            "Меланезия",
+            "Melanésia",
+        ],
+    ),
+    (
+        "057",
+        [
+            "Micronesian Region",
+            "密克罗尼西亚地区",
+            "密克羅尼西亞群島",
+            "ミクロネシア",
+            "미크로네시아 지역",
+            "Mikronesisches Inselgebiet",
+            "région micronésienne",
+            "Región de Micronesia",
+            "Микронезия",
+            "Região da Micronésia",
+        ],
+    ),
+    (
+        "061",
+        [
+            "Polynesia",
+            "玻利尼西亚",
+            "玻里尼西亞",
+            "ポリネシア",
+            "폴리네시아",
+            "Polynesien",
+            "Polynésie",
+            "Polinesia",
+            "Полинезия",
+            "Polinésia",
+        ],
+    ),
+    (
+        "142",
+        [
+            "Asia",
+            "亚洲",
+            "亞洲",
+            "アジア",
+            "아시아",
+            "Asien",
+            "Asie",
+            "Asia",
+            "Азия",
+            "Ásia",
+        ],
+    ),
+    (
+        "143",
+        [
+            "Central Asia",
+            "中亚",
+            "中亞",
+            "中央アジア",
+            "중앙 아시아",
+            "Zentralasien",
+            "Asie centrale",
+            "Asia central",
+            "Центральная Азия",
+            "Ásia Central",
+        ],
+    ),
+    (
+        "145",
+        [
+            "Western Asia",
+            "西亚",
+            "西亞",
+            "西アジア",
+            "서아시아",
+            "Westasien",
+            "Asie de l’Ouest",
+            "Asia occidental",
+            "Западная Азия",
+            "Ásia Ocidental",
+        ],
+    ),
+    (
+        "150",
+        [
+            "Europe",
+            "欧洲",
+            "歐洲",
+            "ヨーロッパ",
+            "유럽",
+            "Europa",
+            "Europe",
+            "Europa",
+            "Европа",
+            "Europa",
+        ],
+    ),
+    (
+        "151",
+        [
+            "Eastern Europe",
+            "东欧",
+            "東歐",
+            "東ヨーロッパ",
+            "동유럽",
+            "Osteuropa",
+            "Europe de l’Est",
+            "Europa oriental",
+            "Восточная Европа",
+            "Europa Oriental",
+        ],
+    ),
+    (
+        "154",
+        [
+            "Northern Europe",
+            "北欧",
+            "北歐",
+            "北ヨーロッパ",
+            "북유럽",
+            "Nordeuropa",
+            "Europe du Nord",
+            "Europa septentrional",
+            "Северная Европа",
+            "Europa Setentrional",
+        ],
+    ),
+    (
+        "155",
+        [
+            "Western Europe",
+            "西欧",
+            "西歐",
+            "西ヨーロッパ",
+            "서유럽",
+            "Westeuropa",
+            "Europe de l’Ouest",
+            "Europa occidental",
+            "Западная Европа",
+            "Europa Ocidental",
+        ],
+    ),
+    (
+        "202",
+        [
+            "Sub-Saharan Africa",
+            "撒哈拉以南非洲",
+            "撒哈拉撒沙漠以南非洲",
+            "サブサハラアフリカ",
+            "사하라 사막 이남 아프리카",
+            "Subsahara-Afrika",
+            "Afrique subsaharienne",
+            "África subsahariana",
+            "Тропическая Африка",
+            "África Subsaariana",
+        ],
+    ),
+    (
+        "419",
+        [
+            "Latin America",
+            "拉丁美洲",
+            "拉丁美洲",
+            "ラテンアメリカ",
+            "라틴 아메리카",
+            "Lateinamerika",
+            "Amérique latine",
+            "Latinoamérica",
+            "Латинская Америка",
+            "América Latina",
+        ],
+    ),
+];
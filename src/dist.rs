@@ -1,27 +1,133 @@
-use axum::http::{header, StatusCode};
+use axum::body::{Body, Bytes};
+use axum::http::{header, HeaderMap, Method, StatusCode};
 use axum::response::{IntoResponse, Response};
 use hex::encode;
-use rust_embed::{EmbeddedFile, RustEmbed};
-use std::convert::From;
+use rust_embed::RustEmbed;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::sync::OnceCell;
+use tracing::error;
+
+use crate::cache;
+use crate::compression::{self, Encoding};
+use crate::config;
 
 #[derive(RustEmbed)]
 #[folder = "dist/"]
 struct Assets;
 
-pub struct StaticFile(String, Option<EmbeddedFile>);
+// 静态文件目录，若设置则优先使用该目录下的文件，未找到时再使用内嵌的文件
+static STATIC_DIR: LazyLock<Option<PathBuf>> =
+    LazyLock::new(|| config::get_env("STATIC_DIR").map(PathBuf::from));
+
+// 静态文件的数据与sha256
+struct Asset {
+    data: Bytes,
+    hash: [u8; 32],
+}
+
+impl Asset {
+    // 长度+hash的一部分
+    fn entity_tag(&self) -> String {
+        // hash为基于内容生成
+        let str = &encode(self.hash)[0..8];
+        format!(r#""{:x}-{str}""#, self.data.len())
+    }
+}
+
+// 内嵌的文件直接引用，避免复制
+fn to_bytes(data: Cow<'static, [u8]>) -> Bytes {
+    match data {
+        Cow::Borrowed(data) => Bytes::from_static(data),
+        Cow::Owned(data) => Bytes::from(data),
+    }
+}
+
+type CompressedCell = Arc<OnceCell<Option<Bytes>>>;
+
+// 压缩后的数据，key为etag与压缩方式
+// 每个key对应一个OnceCell，并发请求时只压缩一次
+#[derive(Default)]
+struct CompressedFiles {
+    items: HashMap<(String, Encoding), CompressedCell>,
+    // 插入顺序，超出数量时淘汰最早的
+    keys: VecDeque<(String, Encoding)>,
+}
+
+static COMPRESSED_FILES: LazyLock<Mutex<CompressedFiles>> =
+    LazyLock::new(|| Mutex::new(CompressedFiles::default()));
+
+// 缓存的压缩文件数量上限，静态文件目录的文件更新后etag会变化，因此需要限制
+static COMPRESSED_FILES_LIMIT: LazyLock<usize> =
+    LazyLock::new(|| config::get_env_number("COMPRESSED_FILES_LIMIT", 256_usize));
+
+fn get_compressed_cell(key: (String, Encoding)) -> CompressedCell {
+    let mut files = COMPRESSED_FILES
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if let Some(cell) = files.items.get(&key) {
+        return cell.clone();
+    }
+    while files.keys.len() >= (*COMPRESSED_FILES_LIMIT).max(1) {
+        let Some(oldest) = files.keys.pop_front() else {
+            break;
+        };
+        files.items.remove(&oldest);
+    }
+    let cell = CompressedCell::default();
+    files.items.insert(key.clone(), cell.clone());
+    files.keys.push_back(key);
+    cell
+}
+
+// br与zstd的高压缩级别耗时较长，因此在blocking线程中压缩
+async fn get_compressed_data(entity_tag: &str, data: Bytes, encoding: Encoding) -> Option<Bytes> {
+    let cell = get_compressed_cell((entity_tag.to_string(), encoding));
+    cell.get_or_init(|| async move {
+        let result =
+            tokio::task::spawn_blocking(move || compression::compress(&data, encoding)).await;
+        match result {
+            Ok(Ok(value)) => Some(Bytes::from(value)),
+            Ok(Err(err)) => {
+                error!(category = "compression", "compress fail: {err}");
+                None
+            }
+            Err(err) => {
+                error!(category = "compression", "compress task fail: {err}");
+                None
+            }
+        }
+    })
+    .await
+    .clone()
+}
+
+fn get_content_type(filename: &str) -> String {
+    mime_guess::from_path(filename)
+        .first_or_octet_stream()
+        .to_string()
+}
+
+pub struct StaticFile {
+    filename: String,
+    file: Option<Asset>,
+    // 请求的method与header，用于HEAD与304的处理
+    method: Method,
+    headers: HeaderMap,
+    // 根据Accept-Encoding压缩后的数据
+    compressed: Option<(Bytes, Encoding)>,
+}
 
 impl IntoResponse for StaticFile {
     fn into_response(self) -> Response {
-        if self.1.is_none() {
+        let Some(file) = self.file else {
             return StatusCode::NOT_FOUND.into_response();
-        }
-        let filename = self.0;
-        // 已保证file不会为空
-        let file = self.1.unwrap();
-        // hash为基于内容生成
-        let str = &encode(file.metadata.sha256_hash())[0..8];
-        // 长度+hash的一部分
-        let entity_tag = format!(r#""{:x}-{str}""#, file.data.len());
+        };
+        let filename = self.filename;
+        let entity_tag = file.entity_tag();
         // 因为html对于网页是入口，避免缓存后更新不及时
         // 因此设置为0
         // 其它js,css会添加版本号，因此无影响
@@ -43,36 +149,358 @@ impl IntoResponse for StaticFile {
         if let Some(s_max_age) = s_max_age {
             max_age = format!("{max_age}, s-maxage={s_max_age}");
         }
-        // 静态文件压缩由前置缓存服务器处理
-        (
-            [
-                // content type
-                (
-                    header::CONTENT_TYPE,
-                    mime_guess::from_path(filename)
-                        .first_or_octet_stream()
-                        .to_string(),
-                ),
-                // 为啥不设置Last-Modified
-                // https://developer.mozilla.org/en-US/docs/Web/HTTP/Caching#heuristic_caching
-                // e tag
-                (header::ETAG, entity_tag),
-                // max age
-                (header::CACHE_CONTROL, max_age),
-            ],
-            file.data,
-        )
-            .into_response()
+        let content_type = get_content_type(&filename);
+
+        let (data, encoding) = match self.compressed {
+            Some((data, encoding)) => (data, Some(encoding)),
+            None => (file.data, None),
+        };
+        // 不同的压缩方式使用不同的etag
+        let entity_tag = match encoding {
+            Some(encoding) => format!(
+                r#"{}-{}""#,
+                entity_tag.trim_end_matches('"'),
+                encoding.as_str()
+            ),
+            None => entity_tag,
+        };
+
+        let headers = [
+            // content type
+            (header::CONTENT_TYPE, content_type),
+            // 为啥不设置Last-Modified
+            // https://developer.mozilla.org/en-US/docs/Web/HTTP/Caching#heuristic_caching
+            // e tag
+            (header::ETAG, entity_tag.clone()),
+            // max age
+            (header::CACHE_CONTROL, max_age),
+            // 根据Accept-Encoding返回不同的数据，因此需要区分
+            (header::VARY, header::ACCEPT_ENCODING.to_string()),
+        ];
+        if cache::is_not_modified(&self.headers, &entity_tag) {
+            let [_, etag, cache_control, vary] = headers;
+            return (StatusCode::NOT_MODIFIED, [etag, cache_control, vary]).into_response();
+        }
+        let content_length = data.len();
+        // HEAD请求只返回header
+        let mut resp = if self.method == Method::HEAD {
+            (headers, Body::empty()).into_response()
+        } else {
+            (headers, data).into_response()
+        };
+        let resp_headers = resp.headers_mut();
+        resp_headers.insert(header::CONTENT_LENGTH, content_length.into());
+        if let Some(encoding) = encoding {
+            resp_headers.insert(
+                header::CONTENT_ENCODING,
+                header::HeaderValue::from_static(encoding.as_str()),
+            );
+        }
+        resp
     }
 }
 
-// 获取资源文件
-fn get_asset(file_path: &str) -> Option<EmbeddedFile> {
-    Assets::get(file_path)
+// 从静态文件目录中读取，仅允许普通的路径，避免访问目录外的文件
+async fn get_asset_from_dir(dir: &Path, file_path: &str) -> Option<Asset> {
+    let file_path = Path::new(file_path);
+    if !file_path
+        .components()
+        .all(|item| matches!(item, Component::Normal(_)))
+    {
+        return None;
+    }
+    let data = tokio::fs::read(dir.join(file_path)).await.ok()?;
+    let hash = Sha256::digest(&data).into();
+    Some(Asset {
+        data: Bytes::from(data),
+        hash,
+    })
+}
+
+// 获取资源文件，静态文件目录优先
+async fn get_asset(static_dir: Option<&Path>, file_path: &str) -> Option<Asset> {
+    if let Some(dir) = static_dir {
+        if let Some(asset) = get_asset_from_dir(dir, file_path).await {
+            return Some(asset);
+        }
+    }
+    Assets::get(file_path).map(|file| Asset {
+        hash: file.metadata.sha256_hash(),
+        data: to_bytes(file.data),
+    })
 }
 
 // 获取静态资源文件
-pub fn get_static_file(file_path: &str) -> StaticFile {
-    let file = get_asset(file_path);
-    StaticFile(file_path.to_string(), file)
+// 无扩展名的路径未找到时返回index.html，用于前端路由
+pub async fn get_static_file(file_path: &str, method: Method, headers: HeaderMap) -> StaticFile {
+    get_static_file_from(STATIC_DIR.as_deref(), file_path, method, headers).await
+}
+
+async fn get_static_file_from(
+    static_dir: Option<&Path>,
+    file_path: &str,
+    method: Method,
+    headers: HeaderMap,
+) -> StaticFile {
+    let mut filename = file_path.to_string();
+    let mut file = get_asset(static_dir, file_path).await;
+    if file.is_none() && Path::new(file_path).extension().is_none() {
+        filename = "index.html".to_string();
+        file = get_asset(static_dir, &filename).await;
+    }
+    // 文本类的文件根据Accept-Encoding返回压缩后的数据
+    let mut compressed = None;
+    if let Some(asset) = &file {
+        let content_type = get_content_type(&filename);
+        if compression::is_compressible(&content_type)
+            && asset.data.len() >= compression::min_size()
+        {
+            if let Some(encoding) = compression::negotiate(&headers) {
+                compressed = get_compressed_data(&asset.entity_tag(), asset.data.clone(), encoding)
+                    .await
+                    .map(|data| (data, encoding));
+            }
+        }
+    }
+    StaticFile {
+        filename,
+        file,
+        method,
+        headers,
+        compressed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn new_static_file(
+        method: Method,
+        if_none_match: Option<&str>,
+        encoding: Option<Encoding>,
+    ) -> StaticFile {
+        let data = Bytes::from("console.log('location');");
+        let mut headers = HeaderMap::new();
+        if let Some(value) = if_none_match {
+            headers.insert(header::IF_NONE_MATCH, HeaderValue::from_str(value).unwrap());
+        }
+        StaticFile {
+            filename: "assets/app.js".to_string(),
+            file: Some(Asset {
+                hash: Sha256::digest(&data).into(),
+                data: data.clone(),
+            }),
+            method,
+            headers,
+            // 测试中仅需区分压缩方式，数据不影响
+            compressed: encoding.map(|encoding| (data.slice(0..10), encoding)),
+        }
+    }
+
+    fn get_header(resp: &Response, name: header::HeaderName) -> String {
+        resp.headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap().to_string())
+            .unwrap_or_default()
+    }
+
+    async fn read_body(resp: Response) -> Bytes {
+        axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn respond_not_modified() {
+        let resp = new_static_file(Method::GET, None, None).into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+        let etag = get_header(&resp, header::ETAG);
+        let cache_control = get_header(&resp, header::CACHE_CONTROL);
+        assert_eq!("public, max-age=31536000, s-maxage=600", cache_control);
+        assert_eq!("text/javascript", get_header(&resp, header::CONTENT_TYPE));
+
+        for if_none_match in [
+            etag.clone(),
+            format!("W/{etag}"),
+            format!(r#""abc", {etag}"#),
+        ] {
+            let resp = new_static_file(Method::GET, Some(&if_none_match), None).into_response();
+            assert_eq!(StatusCode::NOT_MODIFIED, resp.status());
+            assert_eq!(etag, get_header(&resp, header::ETAG));
+            assert_eq!(cache_control, get_header(&resp, header::CACHE_CONTROL));
+            assert_eq!("accept-encoding", get_header(&resp, header::VARY));
+            assert!(read_body(resp).await.is_empty());
+        }
+
+        let resp = new_static_file(Method::GET, Some(r#""abc""#), None).into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+    }
+
+    #[tokio::test]
+    async fn respond_head() {
+        let resp = new_static_file(Method::GET, None, None).into_response();
+        let content_length = get_header(&resp, header::CONTENT_LENGTH);
+        let body = read_body(resp).await;
+        assert_eq!(body.len().to_string(), content_length);
+
+        // HEAD请求无响应体，但Content-Length与GET一致
+        let resp = new_static_file(Method::HEAD, None, None).into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+        assert_eq!(content_length, get_header(&resp, header::CONTENT_LENGTH));
+        assert!(read_body(resp).await.is_empty());
+
+        let resp = new_static_file(Method::HEAD, None, Some(Encoding::Gzip)).into_response();
+        assert_eq!("10", get_header(&resp, header::CONTENT_LENGTH));
+        assert_eq!("gzip", get_header(&resp, header::CONTENT_ENCODING));
+        assert!(read_body(resp).await.is_empty());
+    }
+
+    #[test]
+    fn entity_tag_per_encoding() {
+        let etags: Vec<String> = [
+            None,
+            Some(Encoding::Br),
+            Some(Encoding::Zstd),
+            Some(Encoding::Gzip),
+        ]
+        .into_iter()
+        .map(|encoding| {
+            let resp = new_static_file(Method::GET, None, encoding).into_response();
+            get_header(&resp, header::ETAG)
+        })
+        .collect();
+        assert!(etags[1].ends_with(r#"-br""#));
+        assert!(etags[2].ends_with(r#"-zstd""#));
+        assert!(etags[3].ends_with(r#"-gzip""#));
+        for (index, etag) in etags.iter().enumerate() {
+            assert!(etag.starts_with('"') && etag.ends_with('"'));
+            assert!(!etags[index + 1..].contains(etag));
+        }
+
+        // 其它压缩方式的etag不匹配
+        let resp =
+            new_static_file(Method::GET, Some(&etags[3]), Some(Encoding::Br)).into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+        let resp =
+            new_static_file(Method::GET, Some(&etags[0]), Some(Encoding::Gzip)).into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+        let resp =
+            new_static_file(Method::GET, Some(&etags[3]), Some(Encoding::Gzip)).into_response();
+        assert_eq!(StatusCode::NOT_MODIFIED, resp.status());
+    }
+
+    fn new_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("location-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    async fn get_file(static_dir: Option<&Path>, file_path: &str) -> (StatusCode, String, Bytes) {
+        let resp = get_static_file_from(static_dir, file_path, Method::GET, HeaderMap::new())
+            .await
+            .into_response();
+        let status = resp.status();
+        let content_type = get_header(&resp, header::CONTENT_TYPE);
+        (status, content_type, read_body(resp).await)
+    }
+
+    #[tokio::test]
+    async fn fallback_to_index() {
+        let index = Assets::get("index.html").unwrap().data;
+        // 无扩展名的路径作为前端路由返回index.html
+        for file_path in ["index.html", "some/route", "docs"] {
+            let (status, content_type, body) = get_file(None, file_path).await;
+            assert_eq!(StatusCode::OK, status, "{file_path}");
+            assert_eq!("text/html", content_type);
+            assert_eq!(index.as_ref(), body.as_ref());
+        }
+        for file_path in ["missing.js", "some/route/missing.css"] {
+            let (status, _, _) = get_file(None, file_path).await;
+            assert_eq!(StatusCode::NOT_FOUND, status, "{file_path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn override_by_static_dir() {
+        let dir = new_temp_dir("static");
+        let web = dir.join("web");
+        std::fs::create_dir_all(web.join("assets")).unwrap();
+        std::fs::write(web.join("index.html"), "<html>branded</html>").unwrap();
+        std::fs::write(web.join("assets/app.js"), "console.log(1)").unwrap();
+        std::fs::write(dir.join("secret.txt"), "secret").unwrap();
+
+        let (status, _, body) = get_file(Some(&web), "index.html").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("<html>branded</html>", body);
+        let (_, _, body) = get_file(Some(&web), "some/route").await;
+        assert_eq!("<html>branded</html>", body);
+        let (status, content_type, body) = get_file(Some(&web), "assets/app.js").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("text/javascript", content_type);
+        assert_eq!("console.log(1)", body);
+
+        // 目录中不存在的文件使用内嵌的文件
+        std::fs::remove_file(web.join("index.html")).unwrap();
+        let (status, _, body) = get_file(Some(&web), "index.html").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(
+            Assets::get("index.html").unwrap().data.as_ref(),
+            body.as_ref()
+        );
+
+        // 不允许访问目录外的文件
+        let secret = dir.join("secret.txt");
+        for file_path in [
+            "../secret.txt",
+            "assets/../../secret.txt",
+            "./../secret.txt",
+            secret.to_str().unwrap(),
+        ] {
+            assert!(
+                get_asset_from_dir(&web, file_path).await.is_none(),
+                "{file_path}"
+            );
+            let (status, _, _) = get_file(Some(&web), file_path).await;
+            assert_eq!(StatusCode::NOT_FOUND, status, "{file_path}");
+        }
+        assert!(get_asset_from_dir(&dir, "secret.txt").await.is_some());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn borrow_embedded_data() {
+        static DATA: &[u8] = b"location";
+        let data = to_bytes(Cow::Borrowed(DATA));
+        assert_eq!(DATA.as_ptr(), data.as_ptr());
+    }
+
+    #[tokio::test]
+    async fn compress_once() {
+        let data = Bytes::from("location ".repeat(1000));
+        let (first, second) = tokio::join!(
+            get_compressed_data("compress-once", data.clone(), Encoding::Gzip),
+            get_compressed_data("compress-once", data.clone(), Encoding::Gzip),
+        );
+        let first = first.unwrap();
+        let second = second.unwrap();
+        assert!(first.len() < data.len());
+        // 相同的key只压缩一次，返回同一份数据
+        assert_eq!(first.as_ptr(), second.as_ptr());
+    }
+
+    #[test]
+    fn limit_compressed_files() {
+        for i in 0..*COMPRESSED_FILES_LIMIT + 10 {
+            get_compressed_cell((format!("limit-{i}"), Encoding::Br));
+        }
+        let files = COMPRESSED_FILES.lock().unwrap();
+        assert_eq!(*COMPRESSED_FILES_LIMIT, files.items.len());
+        assert_eq!(files.items.len(), files.keys.len());
+        assert!(!files
+            .items
+            .contains_key(&("limit-0".to_string(), Encoding::Br)));
+    }
 }
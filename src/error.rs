@@ -1,3 +1,5 @@
+use axum::extract::rejection::JsonRejection;
+use axum::http::header::InvalidHeaderValue;
 use axum::http::HeaderValue;
 use axum::{
     http::{header, Method, StatusCode, Uri},
@@ -6,9 +8,14 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::net::AddrParseError;
+use std::sync::LazyLock;
 use tracing::error;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::config;
+use crate::context::{clone_value_from_task_local, REQUEST_ID};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct HTTPError {
     // 出错信息
@@ -23,6 +30,155 @@ pub struct HTTPError {
 
 pub type HTTPResult<T> = Result<T, HTTPError>;
 
+// 出错码，每类出错对应固定的出错码
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub category: &'static str,
+    pub status: u16,
+    pub title: &'static str,
+}
+
+const fn error_code(
+    code: &'static str,
+    category: &'static str,
+    status: u16,
+    title: &'static str,
+) -> ErrorCode {
+    ErrorCode {
+        code,
+        category,
+        status,
+        title,
+    }
+}
+
+// 所有的出错码，新增出错类型时需要添加
+pub static ERROR_CODES: [ErrorCode; 22] = [
+    error_code("BAD_REQUEST", "", 400, "Bad request"),
+    error_code("INTERNAL_ERROR", "", 500, "Internal server error"),
+    error_code("INVALID_IP", "addrParse", 400, "Invalid IP address"),
+    error_code(
+        "RESOLVE_FAILED",
+        "resolve",
+        400,
+        "Failed to resolve the hostname",
+    ),
+    error_code(
+        "BATCH_TOO_LARGE",
+        "batchTooLarge",
+        400,
+        "Too many IPs in a batch",
+    ),
+    error_code("INVALID_BODY", "json", 400, "Invalid request body"),
+    error_code(
+        "UNSUPPORTED_FORMAT",
+        "format",
+        400,
+        "Unsupported response format",
+    ),
+    error_code(
+        "UNSUPPORTED_NAMING",
+        "naming",
+        400,
+        "Unsupported field naming",
+    ),
+    error_code("UNSUPPORTED_LANG", "lang", 400, "Unsupported language"),
+    error_code(
+        "UNAUTHORIZED",
+        "unauthorized",
+        401,
+        "Missing or invalid api key",
+    ),
+    error_code("FORBIDDEN", "forbidden", 403, "Permission denied"),
+    error_code(
+        "GEO_BLOCKED",
+        "geoBlocked",
+        403,
+        "Access from the location is not allowed",
+    ),
+    error_code("NOT_FOUND", "notFound", 404, "Not found"),
+    error_code(
+        "LOCATION_NOT_FOUND",
+        "locationNotFound",
+        404,
+        "No location data for the IP",
+    ),
+    error_code("TIMEOUT", "timeout", 408, "Request timeout"),
+    error_code("RATE_LIMITED", "rateLimited", 429, "Too many requests"),
+    error_code(
+        "ENCODE_FAILED",
+        "encode",
+        500,
+        "Failed to encode the response",
+    ),
+    error_code(
+        "INVALID_HEADER",
+        "invalidHeader",
+        500,
+        "Invalid response header",
+    ),
+    error_code(
+        "METRICS_ERROR",
+        "metrics",
+        500,
+        "Failed to encode the metrics",
+    ),
+    error_code(
+        "RESOLVER_ERROR",
+        "resolver",
+        500,
+        "Failed to initialize the resolver",
+    ),
+    error_code("UPSTREAM_ERROR", "proxy", 502, "Upstream error"),
+    error_code(
+        "RESOLVE_TIMEOUT",
+        "resolveTimeout",
+        504,
+        "Timeout to resolve the hostname",
+    ),
+];
+
+// 根据出错类型获取出错码，未知类型根据状态码使用通用的出错码
+fn get_error_code(category: &str, status: u16) -> &'static ErrorCode {
+    ERROR_CODES
+        .iter()
+        .find(|item| !item.category.is_empty() && item.category == category)
+        .unwrap_or(if status >= 500 {
+            &ERROR_CODES[1]
+        } else {
+            &ERROR_CODES[0]
+        })
+}
+
+// 是否以problem details(RFC 9457)的形式输出出错信息
+static PROBLEM_DETAILS: LazyLock<bool> =
+    LazyLock::new(|| config::get_env_bool("ERROR_PROBLEM_DETAILS", false));
+
+// problem details中type的前缀，指向出错码列表
+static PROBLEM_TYPE_BASE: LazyLock<String> = LazyLock::new(|| {
+    config::get_env("ERROR_PROBLEM_TYPE_BASE").unwrap_or_else(|| "/api/errors".to_string())
+});
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+// RFC 9457
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    // 扩展字段
+    pub code: String,
+    pub category: String,
+}
+
 impl Default for HTTPError {
     fn default() -> Self {
         // 因为默认status为400，因此需要单独实现default
@@ -41,6 +197,24 @@ impl From<AddrParseError> for HTTPError {
     }
 }
 
+impl From<InvalidHeaderValue> for HTTPError {
+    fn from(err: InvalidHeaderValue) -> HTTPError {
+        HTTPError::new_with_category_status(&err.to_string(), "invalidHeader", 500)
+    }
+}
+
+impl From<JsonRejection> for HTTPError {
+    fn from(err: JsonRejection) -> HTTPError {
+        HTTPError::new_with_category_status(&err.body_text(), "json", err.status().as_u16())
+    }
+}
+
+impl From<prometheus::Error> for HTTPError {
+    fn from(err: prometheus::Error) -> HTTPError {
+        HTTPError::new_with_category_status(&err.to_string(), "metrics", 500)
+    }
+}
+
 impl HTTPError {
     pub fn new(message: &str) -> Self {
         Self {
@@ -63,18 +237,62 @@ impl HTTPError {
             ..Default::default()
         }
     }
+    // 未指定出错码时根据出错类型设置
+    pub fn fill_code(&mut self) {
+        if self.code.is_empty() {
+            self.code = get_error_code(&self.category, self.status).code.to_string();
+        }
+    }
+    pub fn to_problem(&self) -> Problem {
+        let error_code = get_error_code(&self.category, self.status);
+        let code = if self.code.is_empty() {
+            error_code.code
+        } else {
+            self.code.as_str()
+        };
+        // 以请求id标识出错的请求
+        let instance = REQUEST_ID
+            .try_with(clone_value_from_task_local)
+            .ok()
+            .map(|value| format!("urn:request-id:{value}"));
+        Problem {
+            problem_type: format!("{}#{code}", PROBLEM_TYPE_BASE.as_str()),
+            title: error_code.title.to_string(),
+            status: self.status,
+            detail: self.message.clone(),
+            instance,
+            code: code.to_string(),
+            category: self.category.clone(),
+        }
+    }
+    // 以problem details的形式输出
+    pub fn into_problem_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST);
+        let mut res = Json(self.to_problem()).into_response();
+        let headers = res.headers_mut();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        res.extensions_mut().insert(self);
+        (status, res).into_response()
+    }
 }
 
 impl IntoResponse for HTTPError {
-    fn into_response(self) -> Response {
+    fn into_response(mut self) -> Response {
+        self.fill_code();
+        if *PROBLEM_DETAILS {
+            return self.into_problem_response();
+        }
         let status = match StatusCode::from_u16(self.status) {
             Ok(status) => status,
             Err(_) => StatusCode::BAD_REQUEST,
         };
         // 对于出错设置为no-cache
-        let mut res = Json(self).into_response();
+        let mut res = Json(self.clone()).into_response();
         res.headers_mut()
             .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        // 添加至extensions，用于转换为其它的响应格式
+        res.extensions_mut().insert(self);
         (status, res).into_response()
     }
 }
@@ -92,3 +310,45 @@ pub async fn handle_error(
     }
     HTTPError::new(&err.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_error_codes() {
+        for (index, item) in ERROR_CODES.iter().enumerate() {
+            assert!(ERROR_CODES[index + 1..]
+                .iter()
+                .all(|other| other.code != item.code
+                    && (item.category.is_empty() || other.category != item.category)));
+        }
+    }
+
+    #[test]
+    fn fill_error_code() {
+        let mut err = HTTPError::new_with_category_status("fail", "resolver", 500);
+        err.fill_code();
+        assert_eq!("RESOLVER_ERROR", err.code);
+
+        let mut err = HTTPError::new_with_category("fail", "resolve");
+        err.fill_code();
+        assert_eq!("RESOLVE_FAILED", err.code);
+
+        // 未知类型根据状态码使用通用的出错码
+        let mut err = HTTPError::new_with_category_status("fail", "unknown", 503);
+        err.fill_code();
+        assert_eq!("INTERNAL_ERROR", err.code);
+    }
+
+    #[tokio::test]
+    async fn problem_with_request_id() {
+        let err = HTTPError::new_with_category("fail", "addrParse");
+        assert_eq!(None, err.to_problem().instance);
+        let problem = REQUEST_ID
+            .scope("abc".to_string(), async { err.to_problem() })
+            .await;
+        assert_eq!(Some("urn:request-id:abc".to_string()), problem.instance);
+        assert_eq!("INVALID_IP", problem.code);
+    }
+}
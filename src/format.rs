@@ -0,0 +1,845 @@
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::error::{HTTPError, HTTPResult, PROBLEM_JSON};
+use crate::ip;
+
+// 响应数据的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Json,
+    Text,
+    Csv,
+    MessagePack,
+    Cbor,
+    Xml,
+}
+
+impl Format {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Text => "text",
+            Format::Csv => "csv",
+            Format::MessagePack => "msgpack",
+            Format::Cbor => "cbor",
+            Format::Xml => "xml",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "json" => Some(Format::Json),
+            "text" | "txt" => Some(Format::Text),
+            "csv" => Some(Format::Csv),
+            "msgpack" | "messagepack" => Some(Format::MessagePack),
+            "cbor" => Some(Format::Cbor),
+            "xml" => Some(Format::Xml),
+            _ => None,
+        }
+    }
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.trim().to_lowercase().as_str() {
+            "application/json" => Some(Format::Json),
+            "text/plain" => Some(Format::Text),
+            "text/csv" => Some(Format::Csv),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Format::MessagePack)
+            }
+            "application/cbor" => Some(Format::Cbor),
+            "application/xml" | "text/xml" => Some(Format::Xml),
+            _ => None,
+        }
+    }
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Format::Json => "application/json",
+            Format::Text => "text/plain; charset=utf-8",
+            Format::Csv => "text/csv; charset=utf-8",
+            Format::MessagePack => "application/msgpack",
+            Format::Cbor => "application/cbor",
+            Format::Xml => "application/xml",
+        }
+    }
+}
+
+// 根据Accept选择格式，q值相同时按出现的顺序，无匹配则使用json
+fn negotiate(headers: &HeaderMap) -> Format {
+    let Some(value) = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return Format::Json;
+    };
+    let mut result: Option<(Format, f32)> = None;
+    for item in value.split(',') {
+        let mut arr = item.split(';');
+        let Some(format) = Format::from_media_type(arr.next().unwrap_or_default()) else {
+            continue;
+        };
+        let q = arr
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if q <= 0.0 {
+            continue;
+        }
+        if result.is_none_or(|(_, current_q)| q > current_q) {
+            result = Some((format, q));
+        }
+    }
+    result.map(|(format, _)| format).unwrap_or_default()
+}
+
+// 获取请求的响应格式，query中的format优先于Accept
+pub fn get_format(query: Option<&str>, headers: &HeaderMap) -> HTTPResult<Format> {
+    let name = query.and_then(|query| {
+        query.split('&').find_map(|item| {
+            let (key, value) = item.split_once('=')?;
+            (key == "format" && !value.is_empty()).then_some(value)
+        })
+    });
+    match name {
+        Some(name) => Format::from_name(name).ok_or_else(|| {
+            HTTPError::new_with_category(&format!("Format {name} is not supported"), "format")
+        }),
+        None => Ok(negotiate(headers)),
+    }
+}
+
+// 字段的命名方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Naming {
+    #[default]
+    CamelCase,
+    SnakeCase,
+}
+
+impl Naming {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "camelcase" | "camel" => Some(Naming::CamelCase),
+            "snake_case" | "snake" => Some(Naming::SnakeCase),
+            _ => None,
+        }
+    }
+}
+
+// 驼峰转换为下划线，如countryName -> country_name
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('_');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// 响应数据的调整：字段选择、命名方式以及批量查询的紧凑格式
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Shape {
+    // 仅返回的字段，按指定的顺序
+    pub fields: Vec<String>,
+    pub naming: Naming,
+    // 批量查询以字段列表+值数组的形式返回
+    pub compact: bool,
+}
+
+impl Shape {
+    // 用于生成etag
+    pub fn cache_key(&self) -> String {
+        format!(
+            "{}:{:?}:{}",
+            self.fields.join(","),
+            self.naming,
+            self.compact
+        )
+    }
+    fn apply(&self, value: Map<String, Value>) -> Map<String, Value> {
+        let rename = |key: String| match self.naming {
+            Naming::CamelCase => key,
+            Naming::SnakeCase => to_snake_case(&key),
+        };
+        if self.fields.is_empty() {
+            return value
+                .into_iter()
+                .map(|(key, value)| (rename(key), value))
+                .collect();
+        }
+        // 字段名支持两种命名方式，不存在的字段忽略
+        let mut value: Map<String, Value> = value
+            .into_iter()
+            .map(|(key, value)| (to_snake_case(&key), value))
+            .collect();
+        let mut result = Map::new();
+        for field in self.fields.iter() {
+            let field = to_snake_case(field);
+            if let Some((key, value)) = value.remove_entry(&field) {
+                let key = match self.naming {
+                    Naming::CamelCase => to_camel_case(&key),
+                    Naming::SnakeCase => key,
+                };
+                result.insert(key, value);
+            }
+        }
+        result
+    }
+}
+
+// 下划线转换为驼峰，如country_name -> countryName
+fn to_camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// 从query中获取fields, naming与compact
+pub fn get_shape(query: Option<&str>) -> HTTPResult<Shape> {
+    let mut shape = Shape::default();
+    for (key, value) in query
+        .unwrap_or_default()
+        .split('&')
+        .filter_map(|item| item.split_once('='))
+    {
+        match key {
+            "fields" => {
+                shape.fields = value
+                    .split(',')
+                    .map(|field| field.trim().to_string())
+                    .filter(|field| !field.is_empty())
+                    .collect();
+            }
+            "naming" => {
+                shape.naming = Naming::from_name(value).ok_or_else(|| {
+                    HTTPError::new_with_category(
+                        &format!("Naming {value} is not supported"),
+                        "naming",
+                    )
+                })?;
+            }
+            "compact" => {
+                shape.compact = matches!(value, "true" | "1");
+            }
+            _ => {}
+        }
+    }
+    Ok(shape)
+}
+
+// 纯文本格式时的输出
+pub trait TextRecord {
+    fn to_text(&self) -> String;
+}
+
+impl TextRecord for ip::Location {
+    fn to_text(&self) -> String {
+        [
+            self.country.as_str(),
+            self.province.as_str(),
+            self.city.as_str(),
+        ]
+        .join(",")
+    }
+}
+
+impl TextRecord for HTTPError {
+    fn to_text(&self) -> String {
+        self.message.clone()
+    }
+}
+
+// 值转换为字符串，用于text与csv
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::String(value) => value.clone(),
+        _ => value.to_string(),
+    }
+}
+
+// xml中列表的元素，列表仅用于批量查询的位置信息
+#[derive(Serialize)]
+struct XmlList<'a, T> {
+    #[serde(rename = "location")]
+    items: &'a [T],
+}
+
+// 紧凑格式，字段名只出现一次
+#[derive(Serialize)]
+struct Compact<'a> {
+    fields: Vec<&'a str>,
+    values: Vec<Vec<&'a Value>>,
+}
+
+#[derive(Serialize)]
+struct XmlFields<'a> {
+    #[serde(rename = "field")]
+    items: &'a [&'a str],
+}
+
+#[derive(Serialize)]
+struct XmlValues<'a> {
+    #[serde(rename = "value")]
+    items: &'a [&'a Value],
+}
+
+#[derive(Serialize)]
+struct XmlCompact<'a> {
+    fields: XmlFields<'a>,
+    #[serde(rename = "values")]
+    values: Vec<XmlValues<'a>>,
+}
+
+pub enum Content<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T: Serialize + TextRecord> Content<T> {
+    fn items(&self) -> &[T] {
+        match self {
+            Content::One(value) => std::slice::from_ref(value),
+            Content::Many(values) => values,
+        }
+    }
+    fn encode(&self, format: Format, root: &str, shape: &Shape) -> Result<Vec<u8>, String> {
+        // 转换为map再调整字段，map会保持字段的顺序
+        let records = self
+            .items()
+            .iter()
+            .map(|item| match serde_json::to_value(item) {
+                Ok(Value::Object(value)) => Ok(shape.apply(value)),
+                Ok(_) => Err("Data should be an object".to_string()),
+                Err(err) => Err(err.to_string()),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        // 每行一条记录，未指定字段时使用默认的文本格式
+        if format == Format::Text {
+            let text = if shape.fields.is_empty() {
+                self.items()
+                    .iter()
+                    .map(|item| item.to_text() + "\n")
+                    .collect::<String>()
+            } else {
+                records
+                    .iter()
+                    .map(|record| {
+                        record
+                            .values()
+                            .map(value_to_string)
+                            .collect::<Vec<_>>()
+                            .join(",")
+                            + "\n"
+                    })
+                    .collect::<String>()
+            };
+            return Ok(text.into_bytes());
+        }
+        if format == Format::Csv {
+            let mut writer = csv::Writer::from_writer(vec![]);
+            if let Some(first) = records.first() {
+                writer
+                    .write_record(first.keys())
+                    .map_err(|err| err.to_string())?;
+            }
+            for record in records.iter() {
+                writer
+                    .write_record(record.values().map(value_to_string))
+                    .map_err(|err| err.to_string())?;
+            }
+            return writer.into_inner().map_err(|err| err.to_string());
+        }
+
+        let compact = match self {
+            Content::Many(_) if shape.compact => {
+                let fields = records
+                    .first()
+                    .map(|record| record.keys().map(|key| key.as_str()).collect())
+                    .unwrap_or_default();
+                let values = records
+                    .iter()
+                    .map(|record| record.values().collect())
+                    .collect();
+                Some(Compact { fields, values })
+            }
+            _ => None,
+        };
+        match format {
+            Format::Json => match (&compact, self) {
+                (Some(compact), _) => serde_json::to_vec(compact),
+                (None, Content::One(_)) => serde_json::to_vec(&records[0]),
+                (None, Content::Many(_)) => serde_json::to_vec(&records),
+            }
+            .map_err(|err| err.to_string()),
+            Format::MessagePack => match (&compact, self) {
+                (Some(compact), _) => rmp_serde::to_vec_named(compact),
+                (None, Content::One(_)) => rmp_serde::to_vec_named(&records[0]),
+                (None, Content::Many(_)) => rmp_serde::to_vec_named(&records),
+            }
+            .map_err(|err| err.to_string()),
+            Format::Cbor => {
+                let mut buf = vec![];
+                match (&compact, self) {
+                    (Some(compact), _) => ciborium::into_writer(compact, &mut buf),
+                    (None, Content::One(_)) => ciborium::into_writer(&records[0], &mut buf),
+                    (None, Content::Many(_)) => ciborium::into_writer(&records, &mut buf),
+                }
+                .map_err(|err| err.to_string())?;
+                Ok(buf)
+            }
+            _ => match (&compact, self) {
+                (Some(compact), _) => {
+                    let value = XmlCompact {
+                        fields: XmlFields {
+                            items: &compact.fields,
+                        },
+                        values: compact
+                            .values
+                            .iter()
+                            .map(|items| XmlValues { items })
+                            .collect(),
+                    };
+                    quick_xml::se::to_string_with_root(root, &value)
+                }
+                (None, Content::One(_)) => quick_xml::se::to_string_with_root(root, &records[0]),
+                (None, Content::Many(_)) => {
+                    quick_xml::se::to_string_with_root(root, &XmlList { items: &records })
+                }
+            }
+            .map(|value| value.into_bytes())
+            .map_err(|err| err.to_string()),
+        }
+    }
+}
+
+// 按指定格式输出的响应
+pub struct Formatted<T> {
+    pub format: Format,
+    pub shape: Shape,
+    // xml的根元素
+    pub root: &'static str,
+    pub content: Content<T>,
+}
+
+impl<T: Serialize + TextRecord> IntoResponse for Formatted<T> {
+    fn into_response(self) -> Response {
+        match self.content.encode(self.format, self.root, &self.shape) {
+            Ok(data) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(self.format.content_type()),
+                )],
+                data,
+            )
+                .into_response(),
+            Err(err) => HTTPError::new_with_category_status(&err, "encode", 500).into_response(),
+        }
+    }
+}
+
+// 将出错信息转换为指定格式，json则根据是否需要problem details转换
+pub fn convert_error(format: Format, problem: bool, mut resp: Response) -> Response {
+    let Some(err) = resp.extensions_mut().remove::<HTTPError>() else {
+        return resp;
+    };
+    let (content_type, data) = match format {
+        Format::Json if !problem => return resp,
+        Format::Json => (
+            PROBLEM_JSON,
+            serde_json::to_vec(&err.to_problem()).map_err(|err| err.to_string()),
+        ),
+        _ => (
+            format.content_type(),
+            Content::One(err).encode(format, "error", &Shape::default()),
+        ),
+    };
+    let Ok(data) = data else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let (mut parts, _) = resp.into_parts();
+    parts
+        .headers
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, data.into())
+}
+
+// Accept中是否有application/problem+json
+pub fn accepts_problem(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .any(|item| item.split(';').next().unwrap_or_default().trim() == PROBLEM_JSON)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn new_headers(accept: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_str(accept).unwrap());
+        headers
+    }
+
+    fn new_location(ip: &str, country: &str, city: &str) -> ip::Location {
+        ip::Location {
+            ip: ip.to_string(),
+            country: country.to_string(),
+            city: city.to_string(),
+            found: true,
+            ..Default::default()
+        }
+    }
+
+    fn new_shape(fields: &str) -> Shape {
+        get_shape(Some(&format!("fields={fields}"))).unwrap()
+    }
+
+    fn encode(content: &Content<ip::Location>, format: Format, shape: &Shape) -> Vec<u8> {
+        content.encode(format, "location", shape).unwrap()
+    }
+
+    fn to_text(data: Vec<u8>) -> String {
+        String::from_utf8(data).unwrap()
+    }
+
+    #[test]
+    fn negotiate_format() {
+        assert_eq!(Format::Json, negotiate(&HeaderMap::new()));
+        assert_eq!(Format::Json, negotiate(&new_headers("text/html, */*")));
+        assert_eq!(Format::Csv, negotiate(&new_headers("text/csv")));
+        // q值高的优先，相同时按出现的顺序
+        for (accept, expected) in [
+            ("application/xml;q=0.5, text/csv;q=0.8", Format::Csv),
+            ("text/csv;q=0.5, application/cbor", Format::Cbor),
+            ("application/msgpack, application/cbor", Format::MessagePack),
+            ("application/cbor;q=0.9, text/xml;q=0.9", Format::Cbor),
+            ("text/plain;q=0, application/xml;q=0.1", Format::Xml),
+            (
+                "application/json;q=0.2, application/x-msgpack;q=0.3",
+                Format::MessagePack,
+            ),
+            (
+                "text/plain; charset=utf-8; q=0.7, text/csv; q=0.6",
+                Format::Text,
+            ),
+            ("text/csv;q=0", Format::Json),
+        ] {
+            assert_eq!(expected, negotiate(&new_headers(accept)), "{accept}");
+        }
+    }
+
+    #[test]
+    fn get_format_from_query() {
+        let headers = new_headers("text/csv");
+        assert_eq!(Format::Csv, get_format(None, &headers).unwrap());
+        assert_eq!(Format::Csv, get_format(Some("format="), &headers).unwrap());
+        assert_eq!(
+            Format::MessagePack,
+            get_format(Some("lang=en&format=msgpack"), &headers).unwrap()
+        );
+        assert_eq!(
+            Format::Text,
+            get_format(Some("format=TXT"), &headers).unwrap()
+        );
+
+        let err = get_format(Some("format=yaml"), &headers).unwrap_err();
+        assert_eq!(400, err.status);
+        assert_eq!("format", err.category);
+        assert_eq!("Format yaml is not supported", err.message);
+    }
+
+    #[test]
+    fn encode_text_and_csv() {
+        let one = Content::One(new_location("1.0.1.1", "CN", "Fuzhou"));
+        let many = Content::Many(vec![
+            new_location("1.0.1.1", "CN", "Fuzhou"),
+            new_location("1.0.0.127", "AU", ""),
+        ]);
+        let shape = Shape::default();
+        assert_eq!("CN,,Fuzhou\n", to_text(encode(&one, Format::Text, &shape)));
+        assert_eq!(
+            "CN,,Fuzhou\nAU,,\n",
+            to_text(encode(&many, Format::Text, &shape))
+        );
+        // 指定字段时按字段输出
+        let shape = new_shape("ip,found");
+        assert_eq!(
+            "1.0.1.1,true\n1.0.0.127,true\n",
+            to_text(encode(&many, Format::Text, &shape))
+        );
+
+        let shape = new_shape("ip,country,city");
+        assert_eq!(
+            "ip,country,city\n1.0.1.1,CN,Fuzhou\n",
+            to_text(encode(&one, Format::Csv, &shape))
+        );
+        assert_eq!(
+            "ip,country,city\n1.0.1.1,CN,Fuzhou\n1.0.0.127,AU,\n",
+            to_text(encode(&many, Format::Csv, &shape))
+        );
+        let csv = to_text(encode(&one, Format::Csv, &Shape::default()));
+        assert!(csv.starts_with("ip,country,countryName,province,city,found,"));
+        assert_eq!(2, csv.lines().count());
+    }
+
+    #[test]
+    fn encode_binary() {
+        let one = Content::One(new_location("1.0.1.1", "CN", "Fuzhou"));
+        let many = Content::Many(vec![
+            new_location("1.0.1.1", "CN", "Fuzhou"),
+            new_location("1.0.0.127", "AU", ""),
+        ]);
+        let shape = new_shape("ip,country,found");
+        let expected_one = json!({"ip": "1.0.1.1", "country": "CN", "found": true});
+        let expected_many = json!([
+            {"ip": "1.0.1.1", "country": "CN", "found": true},
+            {"ip": "1.0.0.127", "country": "AU", "found": true},
+        ]);
+
+        let value: Value = serde_json::from_slice(&encode(&one, Format::Json, &shape)).unwrap();
+        assert_eq!(expected_one, value);
+        let value: Value =
+            rmp_serde::from_slice(&encode(&one, Format::MessagePack, &shape)).unwrap();
+        assert_eq!(expected_one, value);
+        let value: Value =
+            rmp_serde::from_slice(&encode(&many, Format::MessagePack, &shape)).unwrap();
+        assert_eq!(expected_many, value);
+        let value: Value =
+            ciborium::from_reader(encode(&one, Format::Cbor, &shape).as_slice()).unwrap();
+        assert_eq!(expected_one, value);
+        let value: Value =
+            ciborium::from_reader(encode(&many, Format::Cbor, &shape).as_slice()).unwrap();
+        assert_eq!(expected_many, value);
+    }
+
+    #[test]
+    fn encode_xml() {
+        let one = Content::One(new_location("1.0.1.1", "CN", "Fuzhou"));
+        let shape = new_shape("ip,city");
+        assert_eq!(
+            "<location><ip>1.0.1.1</ip><city>Fuzhou</city></location>",
+            to_text(one.encode(Format::Xml, "location", &shape).unwrap())
+        );
+        let many = Content::Many(vec![
+            new_location("1.0.1.1", "CN", "Fuzhou"),
+            new_location("1.0.0.127", "AU", ""),
+        ]);
+        assert_eq!(
+            "<locations><location><ip>1.0.1.1</ip><city>Fuzhou</city></location><location><ip>1.0.0.127</ip><city/></location></locations>",
+            to_text(many.encode(Format::Xml, "locations", &shape).unwrap())
+        );
+    }
+
+    #[test]
+    fn formatted_response() {
+        let resp = Formatted {
+            format: Format::Csv,
+            shape: Shape::default(),
+            root: "location",
+            content: Content::One(new_location("1.0.1.1", "CN", "")),
+        }
+        .into_response();
+        assert_eq!(StatusCode::OK, resp.status());
+        assert_eq!(
+            "text/csv; charset=utf-8",
+            resp.headers().get(header::CONTENT_TYPE).unwrap()
+        );
+    }
+
+    async fn convert(format: Format, problem: bool) -> (StatusCode, String, Vec<u8>) {
+        let resp =
+            HTTPError::new_with_category("Format yaml is not supported", "format").into_response();
+        let resp = convert_error(format, problem, resp);
+        let status = resp.status();
+        let content_type = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, content_type, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn convert_error_to_format() {
+        let (status, content_type, body) = convert(Format::Json, false).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!("application/json", content_type);
+        let value: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!("format", value["category"]);
+        assert_eq!("Format yaml is not supported", value["message"]);
+
+        let (status, content_type, body) = convert(Format::Json, true).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!(PROBLEM_JSON, content_type);
+        let value: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(400, value["status"]);
+        assert_eq!("Format yaml is not supported", value["detail"]);
+        assert_eq!("format", value["category"]);
+        assert!(value["type"]
+            .as_str()
+            .unwrap()
+            .ends_with(value["code"].as_str().unwrap()));
+
+        let (status, content_type, body) = convert(Format::Text, false).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!(Format::Text.content_type(), content_type);
+        assert_eq!("Format yaml is not supported\n", to_text(body));
+
+        let (_, content_type, body) = convert(Format::Csv, false).await;
+        assert_eq!(Format::Csv.content_type(), content_type);
+        let csv = to_text(body);
+        assert!(csv.starts_with("message,category,code,status\n"));
+        assert!(csv.contains("Format yaml is not supported,format,"));
+
+        let (_, content_type, body) = convert(Format::MessagePack, false).await;
+        assert_eq!(Format::MessagePack.content_type(), content_type);
+        let value: Value = rmp_serde::from_slice(&body).unwrap();
+        assert_eq!("format", value["category"]);
+        assert_eq!(400, value["status"]);
+
+        let (_, content_type, body) = convert(Format::Cbor, false).await;
+        assert_eq!(Format::Cbor.content_type(), content_type);
+        let value: Value = ciborium::from_reader(body.as_slice()).unwrap();
+        assert_eq!("format", value["category"]);
+
+        let (_, content_type, body) = convert(Format::Xml, true).await;
+        assert_eq!(Format::Xml.content_type(), content_type);
+        let xml = to_text(body);
+        assert!(xml.starts_with("<error><message>Format yaml is not supported</message>"));
+        assert!(xml.contains("<status>400</status>"));
+
+        // 非出错的响应不转换
+        let resp = convert_error(Format::Xml, false, "ok".into_response());
+        assert_eq!(
+            "text/plain; charset=utf-8",
+            resp.headers().get(header::CONTENT_TYPE).unwrap()
+        );
+    }
+
+    fn to_map(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(value) => value,
+            _ => panic!("should be an object"),
+        }
+    }
+
+    #[test]
+    fn get_shape_from_query() {
+        assert_eq!(Shape::default(), get_shape(None).unwrap());
+        let shape = get_shape(Some("fields=ip,%20,country_name,&naming=snake&compact=1")).unwrap();
+        assert_eq!(vec!["ip", "%20", "country_name"], shape.fields);
+        assert_eq!(Naming::SnakeCase, shape.naming);
+        assert!(shape.compact);
+        let shape = get_shape(Some("naming=camelCase&compact=yes")).unwrap();
+        assert_eq!(Naming::CamelCase, shape.naming);
+        assert!(!shape.compact);
+
+        let err = get_shape(Some("naming=kebab")).unwrap_err();
+        assert_eq!("naming", err.category);
+        assert_eq!("Naming kebab is not supported", err.message);
+
+        // etag需区分不同的shape
+        assert_ne!(
+            new_shape("ip,country").cache_key(),
+            new_shape("country,ip").cache_key()
+        );
+    }
+
+    #[test]
+    fn apply_shape() {
+        let value = to_map(json!({"ip": "1.0.1.1", "countryName": "China", "found": true}));
+
+        // 按指定的顺序返回，支持两种命名方式，忽略不存在的字段
+        let shape = new_shape("found,country_name,unknown,ip");
+        let result = shape.apply(value.clone());
+        assert_eq!(
+            vec!["found", "countryName", "ip"],
+            result.keys().collect::<Vec<_>>()
+        );
+        let shape = new_shape("countryName,IP,ip");
+        assert_eq!(
+            vec!["countryName", "ip"],
+            shape.apply(value.clone()).keys().collect::<Vec<_>>()
+        );
+        assert!(new_shape("unknown").apply(value.clone()).is_empty());
+
+        let shape = get_shape(Some("naming=snake_case")).unwrap();
+        assert_eq!(
+            vec!["ip", "country_name", "found"],
+            shape.apply(value.clone()).keys().collect::<Vec<_>>()
+        );
+        let shape = get_shape(Some("fields=countryName,ip&naming=snake_case")).unwrap();
+        assert_eq!(
+            json!({"country_name": "China", "ip": "1.0.1.1"}),
+            Value::Object(shape.apply(value))
+        );
+    }
+
+    #[test]
+    fn encode_compact() {
+        let many = Content::Many(vec![
+            new_location("1.0.1.1", "CN", "Fuzhou"),
+            new_location("1.0.0.127", "AU", ""),
+        ]);
+        let shape = get_shape(Some("fields=ip,city&naming=snake&compact=true")).unwrap();
+        let value: Value = serde_json::from_slice(&encode(&many, Format::Json, &shape)).unwrap();
+        assert_eq!(
+            json!({
+                "fields": ["ip", "city"],
+                "values": [["1.0.1.1", "Fuzhou"], ["1.0.0.127", ""]],
+            }),
+            value
+        );
+        assert_eq!(
+            "<locations><fields><field>ip</field><field>city</field></fields><values><value>1.0.1.1</value><value>Fuzhou</value></values><values><value>1.0.0.127</value><value/></values></locations>",
+            to_text(many.encode(Format::Xml, "locations", &shape).unwrap())
+        );
+
+        // 单个查询不使用紧凑格式
+        let one = Content::One(new_location("1.0.1.1", "CN", "Fuzhou"));
+        let value: Value = serde_json::from_slice(&encode(&one, Format::Json, &shape)).unwrap();
+        assert_eq!(json!({"ip": "1.0.1.1", "city": "Fuzhou"}), value);
+        // 无数据时字段列表为空
+        let value: Value =
+            serde_json::from_slice(&encode(&Content::Many(vec![]), Format::Json, &shape)).unwrap();
+        assert_eq!(json!({"fields": [], "values": []}), value);
+    }
+
+    #[test]
+    fn accept_problem_json() {
+        assert!(accepts_problem(&new_headers(
+            "application/json, application/problem+json;q=0.9"
+        )));
+        assert!(!accepts_problem(&new_headers("application/json")));
+        assert!(!accepts_problem(&HeaderMap::new()));
+    }
+}
@@ -0,0 +1,256 @@
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::sync::LazyLock;
+use tracing::error;
+
+use crate::config;
+use crate::ip::{self, Location};
+use crate::middleware::{self, GeoPolicy};
+
+pub static X_GEO_IP: HeaderName = HeaderName::from_static("x-geo-ip");
+pub static X_GEO_COUNTRY: HeaderName = HeaderName::from_static("x-geo-country");
+pub static X_GEO_PROVINCE: HeaderName = HeaderName::from_static("x-geo-province");
+pub static X_GEO_CITY: HeaderName = HeaderName::from_static("x-geo-city");
+
+struct ForwardAuthConfig {
+    // 获取客户端ip的请求头，按顺序获取
+    ip_headers: Vec<HeaderName>,
+    // 可信任的代理，仅当请求来自可信代理时才读取请求头
+    // 为空则不信任任何代理，直接使用连接的ip
+    trusted_proxies: Vec<(IpAddr, u8)>,
+    policy: GeoPolicy,
+}
+
+static FORWARD_AUTH_CONFIG: LazyLock<ForwardAuthConfig> = LazyLock::new(|| {
+    let mut ip_headers = config::get_env_list("FORWARD_AUTH_IP_HEADERS");
+    if ip_headers.is_empty() {
+        ip_headers = vec![
+            "x-forwarded-for".to_string(),
+            "x-real-ip".to_string(),
+            "x-envoy-external-address".to_string(),
+        ];
+    }
+    ForwardAuthConfig {
+        ip_headers: ip_headers
+            .iter()
+            .filter_map(|value| HeaderName::from_str(&value.to_lowercase()).ok())
+            .collect(),
+        trusted_proxies: parse_trusted_proxies(&config::get_env_list(
+            "FORWARD_AUTH_TRUSTED_PROXIES",
+        )),
+        policy: GeoPolicy::from_env(),
+    }
+});
+
+// 解析CIDR(如10.0.0.0/8)，单个ip则为全匹配
+fn parse_cidr(value: &str) -> Option<(IpAddr, u8)> {
+    let (ip, prefix) = match value.split_once('/') {
+        Some((ip, prefix)) => (ip, Some(prefix)),
+        None => (value, None),
+    };
+    let ip = IpAddr::from_str(ip.trim()).ok()?;
+    let max = if ip.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(prefix) => prefix.trim().parse::<u8>().ok()?.min(max),
+        None => max,
+    };
+    Some((ip, prefix))
+}
+
+// 无效的配置记录出错日志并忽略，全部无效时则不信任任何代理
+fn parse_trusted_proxies(values: &[String]) -> Vec<(IpAddr, u8)> {
+    values
+        .iter()
+        .filter_map(|value| {
+            let cidr = parse_cidr(value);
+            if cidr.is_none() {
+                error!(
+                    category = "forwardAuth",
+                    value, "trusted proxy is invalid, it will be ignored"
+                );
+            }
+            cidr
+        })
+        .collect()
+}
+
+fn cidr_contains(cidr: &(IpAddr, u8), ip: &IpAddr) -> bool {
+    let (network, prefix) = cidr;
+    match (network, ip) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - *prefix as u32).unwrap_or(0);
+            u32::from(*network) & mask == u32::from(*ip) & mask
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - *prefix as u32).unwrap_or(0);
+            u128::from(*network) & mask == u128::from(*ip) & mask
+        }
+        _ => false,
+    }
+}
+
+impl ForwardAuthConfig {
+    fn is_trusted(&self, ip: &IpAddr) -> bool {
+        self.trusted_proxies
+            .iter()
+            .any(|cidr| cidr_contains(cidr, ip))
+    }
+    // 从可信的请求头中获取客户端ip
+    fn get_client_ip(&self, headers: &HeaderMap, peer: IpAddr) -> IpAddr {
+        if !self.is_trusted(&peer) {
+            return peer;
+        }
+        for name in self.ip_headers.iter() {
+            let Some(value) = headers.get(name).and_then(|value| value.to_str().ok()) else {
+                continue;
+            };
+            let ips: Vec<IpAddr> = value
+                .split(',')
+                .filter_map(|item| IpAddr::from_str(item.trim()).ok())
+                .collect();
+            // 从右往左取第一个非可信代理的ip，左边的值可被客户端伪造
+            let ip = ips
+                .iter()
+                .rev()
+                .find(|ip| !self.is_trusted(ip))
+                .or(ips.first());
+            if let Some(ip) = ip {
+                return *ip;
+            }
+        }
+        peer
+    }
+}
+
+// 将值转换为header value，非ascii字符则使用百分号编码
+fn to_header_value(value: &str) -> HeaderValue {
+    if let Ok(value) = HeaderValue::from_str(value) {
+        return value;
+    }
+    let encoded: String = value
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                (b as char).to_string()
+            } else {
+                format!("%{b:02X}")
+            }
+        })
+        .collect();
+    HeaderValue::from_str(&encoded).unwrap_or_else(|_| HeaderValue::from_static(""))
+}
+
+// 根据位置信息生成geo相关的header
+pub fn new_geo_headers(location: &Location) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(X_GEO_IP.clone(), to_header_value(&location.ip));
+    headers.insert(X_GEO_COUNTRY.clone(), to_header_value(&location.country));
+    headers.insert(X_GEO_PROVINCE.clone(), to_header_value(&location.province));
+    headers.insert(X_GEO_CITY.clone(), to_header_value(&location.city));
+    headers
+}
+
+// 获取客户端ip，仅当请求来自可信代理时才从请求头中获取
+pub fn get_client_ip(headers: &HeaderMap, peer: IpAddr) -> IpAddr {
+    FORWARD_AUTH_CONFIG.get_client_ip(headers, peer)
+}
+
+// 兼容nginx auth_request, traefik ForwardAuth以及envoy ext_authz
+// 允许访问时返回200并设置X-Geo-*响应头，否则返回403
+#[utoipa::path(
+    method(get, head, post, put, patch, delete, options),
+    path = "/forward-auth",
+    tag = "gateway",
+    description = "Any method and any sub path(`/forward-auth/{*path}`) are accepted",
+    responses(
+        (status = 200, description = "Access is allowed", headers(
+            ("X-Geo-Ip" = String, description = "Client ip"),
+            ("X-Geo-Country" = String, description = "Country code"),
+            ("X-Geo-Province" = String, description = "Province"),
+            ("X-Geo-City" = String, description = "City"),
+        )),
+        (status = 403, description = "The location is denied by the geo access rules"),
+    )
+)]
+pub async fn forward_auth(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Response {
+    let auth_config = &FORWARD_AUTH_CONFIG;
+    let client_ip = get_client_ip(&headers, addr.ip());
+    let location = ip::lookup(&client_ip.to_string()).unwrap_or_default();
+    let geo_headers = new_geo_headers(&location);
+    if auth_config.policy.is_enabled() && !auth_config.policy.is_allowed(&location) {
+        let mut resp = middleware::blocked_error().into_response();
+        resp.headers_mut().extend(geo_headers);
+        return resp;
+    }
+    (StatusCode::OK, geo_headers).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_config(trusted_proxies: &[&str]) -> ForwardAuthConfig {
+        let values: Vec<String> = trusted_proxies.iter().map(|v| v.to_string()).collect();
+        ForwardAuthConfig {
+            ip_headers: vec![HeaderName::from_static("x-forwarded-for")],
+            trusted_proxies: parse_trusted_proxies(&values),
+            policy: GeoPolicy::default(),
+        }
+    }
+
+    fn new_headers(forwarded_for: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_str(forwarded_for).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn parse_cidr_values() {
+        assert_eq!(
+            Some(("10.0.0.0".parse().unwrap(), 8)),
+            parse_cidr("10.0.0.0/8")
+        );
+        assert_eq!(Some(("::1".parse().unwrap(), 128)), parse_cidr("::1"));
+        assert_eq!(None, parse_cidr("10.0.0.0/abc"));
+        assert_eq!(None, parse_cidr("proxy.local"));
+    }
+
+    #[test]
+    fn ignore_headers_without_trusted_proxies() {
+        let config = new_config(&[]);
+        let peer: IpAddr = "1.1.1.1".parse().unwrap();
+        assert_eq!(peer, config.get_client_ip(&new_headers("8.8.8.8"), peer));
+    }
+
+    #[test]
+    fn invalid_trusted_proxies_fail_closed() {
+        let config = new_config(&["not-a-cidr", "10.0.0.0/x"]);
+        assert!(config.trusted_proxies.is_empty());
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        assert_eq!(peer, config.get_client_ip(&new_headers("8.8.8.8"), peer));
+    }
+
+    #[test]
+    fn get_client_ip_from_right() {
+        let config = new_config(&["10.0.0.0/8"]);
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        // 客户端伪造的最左侧ip被忽略
+        let headers = new_headers("6.6.6.6, 1.2.3.4, 10.0.0.2");
+        assert_eq!(
+            "1.2.3.4".parse::<IpAddr>().unwrap(),
+            config.get_client_ip(&headers, peer)
+        );
+        // 非可信代理的请求不读取请求头
+        let peer: IpAddr = "1.1.1.1".parse().unwrap();
+        assert_eq!(peer, config.get_client_ip(&headers, peer));
+    }
+}
@@ -95,7 +95,7 @@ fn parse_record_from_file(file: &str, category: LocationCategory, max: i64) -> V
         prev_end = value.end;
         result.push(value);
     }
-    result.sort_by(|a, b| a.end.cmp(&b.end));
+    result.sort_by_key(|a| a.end);
     result
 }
 
@@ -165,6 +165,12 @@ pub fn generate_ip_data(max: i64) {
     println!("province total: {}", province_list.len());
     println!("city total: {}", city_list.len());
 
+    // 数据生成时间，用于计算数据的时效
+    let generated_at_code = format!(
+        "pub static GENERATED_AT: i64 = {};",
+        chrono::Utc::now().timestamp()
+    );
+
     let country_data = serde_json::to_string(&country_list).unwrap();
     let country_code = format!(
         "pub static COUNTRY_LIST: [&str; {}] = {country_data};",
@@ -212,7 +218,8 @@ pub fn generate_ip_data(max: i64) {
 
     let mut file = File::create(filename).unwrap();
 
-    let data = vec![
+    let data = [
+        generated_at_code,
         country_code,
         province_code,
         city_code,
@@ -0,0 +1,689 @@
+use axum::http;
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::net::TcpListener;
+use tokio_stream::{Stream, StreamExt};
+use tonic::body::Body;
+use tonic::metadata::MetadataValue;
+use tonic::transport::server::{TcpConnectInfo, TcpIncoming};
+use tonic::{Code, Request, Response, Status, Streaming};
+use tower::{Layer, Service};
+use tracing::{error, info};
+
+use crate::config;
+use crate::error::{HTTPError, HTTPResult};
+use crate::forward_auth;
+use crate::i18n::{self, Lang};
+use crate::ip;
+use crate::lookup;
+use crate::middleware::{self, ApiKey, GeoPolicy, Scope};
+
+mod pb {
+    tonic::include_proto!("location.v1");
+}
+
+use pb::location_service_server::{LocationService, LocationServiceServer};
+
+// 用于grpc reflection的proto描述
+const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/location_descriptor.bin"));
+
+// grpc服务的监听地址，未设置则不启用
+pub fn get_addr() -> Option<SocketAddr> {
+    let addr = config::get_env("GRPC_ADDR")?;
+    match addr.parse() {
+        Ok(addr) => Some(addr),
+        Err(err) => {
+            error!(category = "grpc", addr, "parse grpc addr fail: {err}");
+            None
+        }
+    }
+}
+
+// http状态码对应的grpc状态码，出错码通过metadata的error-code返回
+impl From<HTTPError> for Status {
+    fn from(mut err: HTTPError) -> Self {
+        err.fill_code();
+        let code = match err.status {
+            400 => Code::InvalidArgument,
+            401 => Code::Unauthenticated,
+            403 => Code::PermissionDenied,
+            404 => Code::NotFound,
+            408 | 504 => Code::DeadlineExceeded,
+            429 => Code::ResourceExhausted,
+            502 => Code::Unavailable,
+            _ => Code::Internal,
+        };
+        let mut status = Status::new(code, err.message);
+        if let Ok(value) = MetadataValue::try_from(err.code.as_str()) {
+            status.metadata_mut().insert("error-code", value);
+        }
+        status
+    }
+}
+
+impl From<ip::Location> for pb::Location {
+    fn from(value: ip::Location) -> Self {
+        let confidence = match value.confidence {
+            ip::Confidence::None => pb::Confidence::None,
+            ip::Confidence::Country => pb::Confidence::Country,
+            ip::Confidence::Province => pb::Confidence::Province,
+            ip::Confidence::City => pb::Confidence::City,
+        };
+        Self {
+            ip: value.ip,
+            country: value.country,
+            country_name: value.country_name,
+            province: value.province,
+            city: value.city,
+            found: value.found,
+            confidence: confidence.into(),
+            continent: value.continent,
+            continent_name: value.continent_name,
+            region: value.region,
+            region_name: value.region_name,
+            subregion: value.subregion,
+            subregion_name: value.subregion_name,
+            eu: value.eu,
+            eea: value.eea,
+            calling_code: value.calling_code,
+            currency: value.currency,
+        }
+    }
+}
+
+// 需要访问控制的服务路径，health与reflection不校验
+const SERVICE_PATH: &str = "/location.v1.LocationService/";
+
+// 各方法所需的权限
+fn get_scope(method: &str) -> Scope {
+    match method {
+        "Lookup" | "LookupHost" => Scope::Lookup,
+        _ => Scope::Batch,
+    }
+}
+
+// 与http一致的访问控制，依次为地区、认证失败次数、api key与限流
+// api key通过metadata的x-api-key传递
+// 返回请求方，流式查询时每条消息均需要按请求方限流
+fn check_access(policy: &GeoPolicy, req: &http::Request<Body>) -> HTTPResult<Option<Caller>> {
+    let Some(method) = req.uri().path().strip_prefix(SERVICE_PATH) else {
+        return Ok(None);
+    };
+    let Some(addr) = req
+        .extensions()
+        .get::<TcpConnectInfo>()
+        .and_then(|info| info.remote_addr())
+    else {
+        return Err(HTTPError::new_with_category_status(
+            "Client ip is unknown",
+            "clientIp",
+            500,
+        ));
+    };
+    // 仅信任可信代理设置的请求头
+    let ip = forward_auth::get_client_ip(req.headers(), addr.ip());
+    if policy.is_enabled() {
+        let (allowed, location) = policy.check_ip(&ip);
+        if !allowed {
+            info!(
+                category = "geoBlocked",
+                ip = ip.to_string(),
+                country = location.country,
+                province = location.province,
+            );
+            return Err(middleware::blocked_error());
+        }
+    }
+    middleware::check_auth_failures(ip)?;
+    let key = req
+        .headers()
+        .get("x-api-key")
+        .and_then(|value| value.to_str().ok());
+    let api_key = middleware::verify_api_key(key, get_scope(method)).inspect_err(|err| {
+        if err.status == 401 {
+            middleware::record_auth_failure(ip);
+        }
+    })?;
+    middleware::check_rate_limit(ip, api_key.as_deref())?;
+    Ok(Some(Caller { ip, api_key }))
+}
+
+// 通过访问控制的请求方，添加至request extensions
+#[derive(Clone, Debug)]
+struct Caller {
+    ip: IpAddr,
+    api_key: Option<Arc<ApiKey>>,
+}
+
+#[derive(Clone)]
+struct AccessLayer {
+    policy: Arc<GeoPolicy>,
+}
+
+impl AccessLayer {
+    fn new(policy: GeoPolicy) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl<S> Layer<S> for AccessLayer {
+    type Service = Access<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Access {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Access<S> {
+    inner: S,
+    policy: Arc<GeoPolicy>,
+}
+
+impl<S> Service<http::Request<Body>> for Access<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<Body>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<Body>) -> Self::Future {
+        match check_access(&self.policy, &req) {
+            Ok(Some(caller)) => {
+                req.extensions_mut().insert(caller);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                let resp = Status::from(err).into_http();
+                return Box::pin(async move { Ok(resp) });
+            }
+        }
+        Box::pin(self.inner.call(req))
+    }
+}
+
+fn get_lang(name: &str) -> HTTPResult<Lang> {
+    if name.is_empty() {
+        return Ok(i18n::default_lang());
+    }
+    Lang::from_name(name).ok_or_else(|| {
+        HTTPError::new_with_category(&format!("Lang {name} is not supported"), "lang")
+    })
+}
+
+// 与http共用查询，包括strict模式
+fn lookup_ip(req: &pb::LookupRequest) -> HTTPResult<pb::Location> {
+    let strict = req.strict.unwrap_or_else(lookup::default_strict);
+    let location = lookup::lookup_ip(&req.ip, strict, get_lang(&req.lang)?)?;
+    Ok(location.into())
+}
+
+// check为该消息的限流，被限制时返回该消息的出错而不中断流
+fn lookup_stream_item(
+    req: pb::LookupRequest,
+    check: impl FnOnce() -> HTTPResult<()>,
+) -> pb::LookupStreamResponse {
+    match check().and_then(|_| lookup_ip(&req)) {
+        Ok(location) => pb::LookupStreamResponse {
+            ip: req.ip,
+            location: Some(location),
+            ..Default::default()
+        },
+        Err(mut err) => {
+            err.fill_code();
+            pb::LookupStreamResponse {
+                ip: req.ip,
+                error_code: err.code,
+                error_message: err.message,
+                ..Default::default()
+            }
+        }
+    }
+}
+
+type RateLimitFn = fn(IpAddr, Option<&ApiKey>) -> HTTPResult<()>;
+
+struct Location {
+    // 单个流最多的消息数
+    stream_max_size: usize,
+    // 流式查询每条消息的限流，与http共用令牌桶
+    check_rate_limit: RateLimitFn,
+}
+
+impl Location {
+    fn from_env() -> Self {
+        Self {
+            stream_max_size: config::get_env_number("GRPC_STREAM_MAX_SIZE", 1000_usize),
+            check_rate_limit: middleware::check_rate_limit,
+        }
+    }
+}
+
+type LookupStream = Pin<Box<dyn Stream<Item = Result<pb::LookupStreamResponse, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl LocationService for Location {
+    async fn lookup(
+        &self,
+        req: Request<pb::LookupRequest>,
+    ) -> Result<Response<pb::Location>, Status> {
+        let location = lookup_ip(req.get_ref())?;
+        Ok(Response::new(location))
+    }
+    async fn batch_lookup(
+        &self,
+        req: Request<pb::BatchLookupRequest>,
+    ) -> Result<Response<pb::BatchLookupResponse>, Status> {
+        let req = req.into_inner();
+        let locations = lookup::lookup_ips(&req.ips, get_lang(&req.lang)?)?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(Response::new(pb::BatchLookupResponse { locations }))
+    }
+
+    type LookupStreamStream = LookupStream;
+
+    async fn lookup_stream(
+        &self,
+        req: Request<Streaming<pb::LookupRequest>>,
+    ) -> Result<Response<Self::LookupStreamStream>, Status> {
+        let caller = req.extensions().get::<Caller>().cloned();
+        let max = self.stream_max_size;
+        let check_rate_limit = self.check_rate_limit;
+        let mut count = 0;
+        let output = req.into_inner().map(move |item| {
+            count += 1;
+            // 超出数量时返回出错并结束流
+            if count > max {
+                return Err(HTTPError::new_with_category(
+                    &format!("The count of messages should be less than or equal to {max}"),
+                    "batchTooLarge",
+                )
+                .into());
+            }
+            let item = item?;
+            Ok(lookup_stream_item(item, || match &caller {
+                Some(caller) => check_rate_limit(caller.ip, caller.api_key.as_deref()),
+                None => Ok(()),
+            }))
+        });
+        Ok(Response::new(Box::pin(output)))
+    }
+
+    async fn lookup_host(
+        &self,
+        req: Request<pb::LookupHostRequest>,
+    ) -> Result<Response<pb::BatchLookupResponse>, Status> {
+        let req = req.into_inner();
+        let strict = req.strict.unwrap_or_else(lookup::default_strict);
+        let locations = lookup::lookup_host(&req.host, strict, get_lang(&req.lang)?)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(Response::new(pb::BatchLookupResponse { locations }))
+    }
+}
+
+// 启动grpc服务，包括health与reflection
+// draining为收到退出信号时(摘除等待前)，shutdown为摘除等待完成后
+pub async fn serve(
+    addr: SocketAddr,
+    draining: impl Future<Output = ()> + Send + 'static,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) {
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            error!(category = "grpc", "listen grpc addr fail: {err}");
+            return;
+        }
+    };
+    info!("grpc listening on {addr}");
+    serve_with_listener(
+        listener,
+        GeoPolicy::from_env(),
+        Location::from_env(),
+        draining,
+        shutdown,
+    )
+    .await;
+}
+
+async fn serve_with_listener(
+    listener: TcpListener,
+    policy: GeoPolicy,
+    service: Location,
+    draining: impl Future<Output = ()> + Send + 'static,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) {
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    health_reporter
+        .set_serving::<LocationServiceServer<Location>>()
+        .await;
+    // 收到退出信号即设置为not serving，与readyz一致，便于摘除等待期间不再有新的流量
+    let drain_task = tokio::spawn(async move {
+        draining.await;
+        health_reporter
+            .set_not_serving::<LocationServiceServer<Location>>()
+            .await;
+    });
+    let reflection_service = match tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()
+    {
+        Ok(service) => service,
+        Err(err) => {
+            error!(category = "grpc", "build reflection service fail: {err}");
+            return;
+        }
+    };
+    let result = tonic::transport::Server::builder()
+        // 限流、地区与api key的校验与http一致
+        .layer(AccessLayer::new(policy))
+        .add_service(health_service)
+        .add_service(reflection_service)
+        .add_service(LocationServiceServer::new(service))
+        .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown)
+        .await;
+    drain_task.abort();
+    if let Err(err) = result {
+        error!(category = "grpc", "grpc server fail: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pb::location_service_client::LocationServiceClient;
+    use std::collections::HashSet;
+    use tokio::sync::oneshot;
+    use tonic::transport::Channel;
+
+    // 在本地随机端口启动grpc服务，返回client与停止服务的sender
+    async fn start_server(
+        policy: GeoPolicy,
+    ) -> (LocationServiceClient<Channel>, oneshot::Sender<()>) {
+        start_server_with(
+            policy,
+            Location {
+                stream_max_size: 1000,
+                check_rate_limit: |_, _| Ok(()),
+            },
+        )
+        .await
+    }
+
+    async fn start_server_with(
+        policy: GeoPolicy,
+        service: Location,
+    ) -> (LocationServiceClient<Channel>, oneshot::Sender<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = oneshot::channel::<()>();
+        tokio::spawn(serve_with_listener(
+            listener,
+            policy,
+            service,
+            std::future::pending(),
+            async move {
+                let _ = rx.await;
+            },
+        ));
+        let client = LocationServiceClient::connect(format!("http://{addr}"))
+            .await
+            .unwrap();
+        (client, tx)
+    }
+
+    fn new_lookup_request(ip: &str, strict: Option<bool>) -> pb::LookupRequest {
+        pb::LookupRequest {
+            ip: ip.to_string(),
+            lang: "en".to_string(),
+            strict,
+        }
+    }
+
+    #[tokio::test]
+    async fn lookup() {
+        let (mut client, _tx) = start_server(GeoPolicy::default()).await;
+        let location = client
+            .lookup(new_lookup_request(" 1.0.1.1 ", None))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!("1.0.1.1", location.ip);
+        assert_eq!("CN", location.country);
+        assert!(location.found);
+
+        let status = client
+            .lookup(new_lookup_request("abc", None))
+            .await
+            .unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+        assert_eq!("INVALID_IP", status.metadata().get("error-code").unwrap());
+
+        // strict模式下无位置数据返回NOT_FOUND
+        let status = client
+            .lookup(new_lookup_request("192.0.2.1", Some(true)))
+            .await
+            .unwrap_err();
+        assert_eq!(Code::NotFound, status.code());
+        assert_eq!(
+            "LOCATION_NOT_FOUND",
+            status.metadata().get("error-code").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_lookup() {
+        let (mut client, _tx) = start_server(GeoPolicy::default()).await;
+        let locations = client
+            .batch_lookup(pb::BatchLookupRequest {
+                ips: vec!["1.0.1.1".to_string(), "192.0.2.1".to_string()],
+                lang: "en".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .locations;
+        assert_eq!(2, locations.len());
+        assert!(locations[0].found);
+        assert!(!locations[1].found);
+
+        let status = client
+            .batch_lookup(pb::BatchLookupRequest {
+                ips: vec!["1.0.1.1".to_string(), "abc".to_string()],
+                lang: "".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+    }
+
+    #[tokio::test]
+    async fn lookup_stream() {
+        let (mut client, _tx) = start_server(GeoPolicy::default()).await;
+        let requests = tokio_stream::iter(vec![
+            new_lookup_request("1.0.1.1", None),
+            new_lookup_request("abc", None),
+        ]);
+        let mut stream = client.lookup_stream(requests).await.unwrap().into_inner();
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!("CN", first.location.unwrap().country);
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!("INVALID_IP", second.error_code);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn limit_stream_messages() {
+        let (mut client, _tx) = start_server_with(
+            GeoPolicy::default(),
+            Location {
+                stream_max_size: 2,
+                check_rate_limit: |_, _| Ok(()),
+            },
+        )
+        .await;
+        let requests = tokio_stream::iter(vec![new_lookup_request("1.0.1.1", None); 3]);
+        let mut stream = client.lookup_stream(requests).await.unwrap().into_inner();
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_ok());
+        // 超出数量后返回出错并结束流
+        let status = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+        assert_eq!(
+            "BATCH_TOO_LARGE",
+            status.metadata().get("error-code").unwrap()
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn rate_limit_stream_messages() {
+        // 仅允许第一条消息通过
+        static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let (mut client, _tx) = start_server_with(
+            GeoPolicy::default(),
+            Location {
+                stream_max_size: 1000,
+                check_rate_limit: |_, _| {
+                    if COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed) == 0 {
+                        return Ok(());
+                    }
+                    Err(HTTPError::new_with_category_status(
+                        "Too many requests",
+                        "rateLimited",
+                        429,
+                    ))
+                },
+            },
+        )
+        .await;
+        let requests = tokio_stream::iter(vec![new_lookup_request("1.0.1.1", None); 3]);
+        let mut stream = client.lookup_stream(requests).await.unwrap().into_inner();
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!("CN", first.location.unwrap().country);
+        // 被限制的消息返回出错而不中断流
+        for _ in 0..2 {
+            let item = stream.next().await.unwrap().unwrap();
+            assert_eq!("RATE_LIMITED", item.error_code);
+            assert!(item.location.is_none());
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn block_by_geo_policy() {
+        let policy = GeoPolicy {
+            deny_countries: HashSet::from(["zz".to_string()]),
+            allow_unknown: false,
+            ..Default::default()
+        };
+        let (mut client, _tx) = start_server(policy).await;
+        // 127.0.0.1无位置数据，不允许未知位置时被禁止
+        let status = client
+            .lookup(new_lookup_request("1.0.1.1", None))
+            .await
+            .unwrap_err();
+        assert_eq!(Code::PermissionDenied, status.code());
+        assert_eq!("GEO_BLOCKED", status.metadata().get("error-code").unwrap());
+    }
+
+    #[tokio::test]
+    async fn not_serving_when_draining() {
+        use tonic_health::pb::health_check_response::ServingStatus;
+        use tonic_health::pb::health_client::HealthClient;
+        use tonic_health::pb::HealthCheckRequest;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (draining_tx, draining_rx) = oneshot::channel::<()>();
+        let (_shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        tokio::spawn(serve_with_listener(
+            listener,
+            GeoPolicy::default(),
+            Location::from_env(),
+            async move {
+                let _ = draining_rx.await;
+            },
+            async move {
+                let _ = shutdown_rx.await;
+            },
+        ));
+        let channel = Channel::from_shared(format!("http://{addr}"))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        let mut client = HealthClient::new(channel);
+        let request = HealthCheckRequest {
+            service: "location.v1.LocationService".to_string(),
+        };
+        let check = |mut client: HealthClient<Channel>, request: HealthCheckRequest| async move {
+            client.check(request).await.unwrap().into_inner().status
+        };
+        assert_eq!(
+            ServingStatus::Serving as i32,
+            check(client.clone(), request.clone()).await
+        );
+
+        // 收到退出信号后，服务停止前即为not serving
+        draining_tx.send(()).unwrap();
+        let mut status = 0;
+        for _ in 0..50 {
+            status = check(client.clone(), request.clone()).await;
+            if status == ServingStatus::NotServing as i32 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(ServingStatus::NotServing as i32, status);
+        // 服务仍可访问
+        assert!(client.check(request).await.is_ok());
+    }
+
+    #[test]
+    fn require_client_ip() {
+        let policy = GeoPolicy::default();
+        // health与reflection不校验
+        let req = http::Request::builder()
+            .uri("/grpc.health.v1.Health/Check")
+            .body(Body::empty())
+            .unwrap();
+        assert!(check_access(&policy, &req).is_ok());
+
+        let req = http::Request::builder()
+            .uri("/location.v1.LocationService/Lookup")
+            .body(Body::empty())
+            .unwrap();
+        let err = check_access(&policy, &req).unwrap_err();
+        assert_eq!("clientIp", err.category);
+    }
+
+    #[test]
+    fn method_scopes() {
+        assert_eq!(Scope::Lookup, get_scope("Lookup"));
+        assert_eq!(Scope::Lookup, get_scope("LookupHost"));
+        assert_eq!(Scope::Batch, get_scope("BatchLookup"));
+        assert_eq!(Scope::Batch, get_scope("LookupStream"));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::config;
+use crate::ip;
+use crate::ip_data;
+use serde::Serialize;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+use std::time::Instant;
+use tokio::sync::watch;
+use utoipa::ToSchema;
+
+static STARTED_AT: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+// 关闭中的状态，使用watch便于grpc health等在收到信号时即时更新
+static SHUTTING_DOWN: LazyLock<watch::Sender<bool>> = LazyLock::new(|| watch::Sender::new(false));
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Liveness {
+    pub status: String,
+    // 运行时长(秒)
+    pub uptime: u64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetCheck {
+    pub loaded: bool,
+    pub ipv4_records: usize,
+    pub ipv6_records: usize,
+    pub generated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleCheck {
+    pub ip: String,
+    pub expected: String,
+    pub actual: String,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Readiness {
+    pub status: String,
+    pub ready: bool,
+    pub shutting_down: bool,
+    pub dataset: DatasetCheck,
+    pub samples: Vec<SampleCheck>,
+}
+
+// 记录启动时间
+pub fn init() {
+    LazyLock::force(&STARTED_AT);
+}
+
+// 设置为关闭中，readyz则返回失败
+pub fn set_shutting_down() {
+    SHUTTING_DOWN.send_replace(true);
+}
+
+// 等待进入关闭中的状态(收到退出信号，摘除等待开始前)
+pub async fn wait_shutting_down() {
+    let mut rx = SHUTTING_DOWN.subscribe();
+    let _ = rx.wait_for(|shutting_down| *shutting_down).await;
+}
+
+fn status_text(ok: bool) -> String {
+    if ok {
+        "ok".to_string()
+    } else {
+        "unavailable".to_string()
+    }
+}
+
+pub fn liveness() -> Liveness {
+    Liveness {
+        status: status_text(true),
+        uptime: STARTED_AT.elapsed().as_secs(),
+    }
+}
+
+fn check_dataset() -> DatasetCheck {
+    let ipv4_records = ip_data::IPV4_LIST.len();
+    let ipv6_records = ip_data::IPV6_LIST.len();
+    let loaded = ipv4_records > 0
+        && ipv6_records > 0
+        && ipv4_records == ip_data::IPV4_LOCATION_LIST.len()
+        && ipv6_records == ip_data::IPV6_LOCATION_LIST.len();
+    DatasetCheck {
+        loaded,
+        ipv4_records,
+        ipv6_records,
+        generated_at: ip_data::GENERATED_AT,
+    }
+}
+
+fn get_country(location: &[usize; 3]) -> String {
+    ip_data::COUNTRY_LIST
+        .get(location[0])
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+// 从数据集中选取首、中、尾有国家信息的记录作为样本
+fn pick_samples<T: Copy>(
+    values: &[T],
+    locations: &[[usize; 3]],
+    to_ip: impl Fn(T) -> String,
+) -> Vec<(String, String)> {
+    let items: Vec<(String, String)> = values
+        .iter()
+        .zip(locations.iter())
+        .map(|(value, location)| (to_ip(*value), get_country(location)))
+        .filter(|(_, country)| !country.is_empty())
+        .collect();
+    if items.is_empty() {
+        return vec![];
+    }
+    let mut indexes = vec![0, items.len() / 2, items.len() - 1];
+    indexes.dedup();
+    indexes
+        .into_iter()
+        .map(|index| items[index].clone())
+        .collect()
+}
+
+// 样本ip，默认从数据集中选取，可通过READYZ_SAMPLES覆盖，格式为: 1.0.132.192=TH,2001:db8::1=US
+fn get_samples() -> Vec<(String, String)> {
+    let samples: Vec<(String, String)> = config::get_env_list("READYZ_SAMPLES")
+        .iter()
+        .filter_map(|item| {
+            let (ip, country) = item.split_once('=')?;
+            Some((ip.trim().to_string(), country.trim().to_string()))
+        })
+        .collect();
+    if !samples.is_empty() {
+        return samples;
+    }
+    let mut samples = pick_samples(&ip_data::IPV4_LIST, &ip_data::IPV4_LOCATION_LIST, |v| {
+        Ipv4Addr::from(v).to_string()
+    });
+    samples.extend(pick_samples(
+        &ip_data::IPV6_LIST,
+        &ip_data::IPV6_LOCATION_LIST,
+        |v| Ipv6Addr::from(v).to_string(),
+    ));
+    samples
+}
+
+fn check_samples(samples: Vec<(String, String)>) -> Vec<SampleCheck> {
+    samples
+        .into_iter()
+        .map(|(ip, expected)| {
+            let actual = ip::lookup(&ip)
+                .map(|location| location.country)
+                .unwrap_or_default();
+            SampleCheck {
+                passed: actual == expected,
+                ip,
+                expected,
+                actual,
+            }
+        })
+        .collect()
+}
+
+pub fn readiness() -> Readiness {
+    check_readiness(*SHUTTING_DOWN.borrow(), get_samples())
+}
+
+// 无样本时视为未就绪，避免数据异常时误判
+fn check_readiness(shutting_down: bool, samples: Vec<(String, String)>) -> Readiness {
+    let dataset = check_dataset();
+    let samples = check_samples(samples);
+    let ready = !shutting_down
+        && dataset.loaded
+        && !samples.is_empty()
+        && samples.iter().all(|item| item.passed);
+    Readiness {
+        status: status_text(ready),
+        ready,
+        shutting_down,
+        dataset,
+        samples,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_samples() {
+        let samples = get_samples();
+        assert!(!samples.is_empty());
+        let readiness = check_readiness(false, samples);
+        assert!(readiness.ready);
+        assert_eq!("ok", readiness.status);
+        assert!(readiness.samples.iter().all(|item| item.passed));
+    }
+
+    #[test]
+    fn not_ready_with_mismatched_sample() {
+        let readiness = check_readiness(
+            false,
+            vec![
+                ("1.0.1.1".to_string(), "CN".to_string()),
+                ("1.0.1.1".to_string(), "US".to_string()),
+            ],
+        );
+        assert!(!readiness.ready);
+        assert_eq!("unavailable", readiness.status);
+        assert!(readiness.samples[0].passed);
+        assert!(!readiness.samples[1].passed);
+        assert_eq!("CN", readiness.samples[1].actual);
+
+        // 无样本或关闭中均未就绪
+        assert!(!check_readiness(false, vec![]).ready);
+        assert!(!check_readiness(true, get_samples()).ready);
+    }
+}
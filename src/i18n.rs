@@ -0,0 +1,278 @@
+use axum::http::{header, HeaderMap};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::LazyLock;
+use tracing::error;
+
+use crate::config;
+use crate::country_data::{CONTINENT_NAMES, COUNTRY_NAMES, COUNTRY_NAME_LANGS, REGION_NAMES};
+use crate::error::{HTTPError, HTTPResult};
+use crate::ip::Location;
+
+// 语言，值为COUNTRY_NAME_LANGS中的下标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lang(usize);
+
+impl Lang {
+    pub fn as_str(&self) -> &'static str {
+        COUNTRY_NAME_LANGS[self.0]
+    }
+    // 支持zh_CN, zh-Hans, en-US等写法
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().replace('_', "-").to_lowercase();
+        let name = match name.as_str() {
+            "zh" | "zh-hans" | "zh-sg" | "zh-hans-cn" => "zh-cn",
+            "zh-hant" | "zh-hk" | "zh-mo" | "zh-hant-tw" => "zh-tw",
+            value => value,
+        };
+        let find = |name: &str| {
+            COUNTRY_NAME_LANGS
+                .iter()
+                .position(|value| value.eq_ignore_ascii_case(name))
+                .map(Lang)
+        };
+        find(name).or_else(|| find(name.split('-').next().unwrap_or_default()))
+    }
+}
+
+// 默认的语言
+static DEFAULT_LANG: LazyLock<Lang> = LazyLock::new(|| {
+    config::get_env("DEFAULT_LANG")
+        .and_then(|value| Lang::from_name(&value))
+        .unwrap_or(Lang(0))
+});
+
+pub fn default_lang() -> Lang {
+    *DEFAULT_LANG
+}
+
+// 根据Accept-Language选择语言，无匹配则返回None
+fn negotiate(headers: &HeaderMap) -> Option<Lang> {
+    let value = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())?;
+    let mut result: Option<(Lang, f32)> = None;
+    for item in value.split(',') {
+        let mut arr = item.split(';');
+        let Some(lang) = Lang::from_name(arr.next().unwrap_or_default()) else {
+            continue;
+        };
+        let q = arr
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if q <= 0.0 {
+            continue;
+        }
+        if result.is_none_or(|(_, current_q)| q > current_q) {
+            result = Some((lang, q));
+        }
+    }
+    result.map(|(lang, _)| lang)
+}
+
+// 获取请求的语言，query中的lang优先于Accept-Language
+pub fn get_lang(query: Option<&str>, headers: &HeaderMap) -> HTTPResult<Lang> {
+    let name = query.and_then(|query| {
+        query.split('&').find_map(|item| {
+            let (key, value) = item.split_once('=')?;
+            (key == "lang" && !value.is_empty()).then_some(value)
+        })
+    });
+    match name {
+        Some(name) => Lang::from_name(name).ok_or_else(|| {
+            HTTPError::new_with_category(&format!("Lang {name} is not supported"), "lang")
+        }),
+        None => Ok(negotiate(headers).unwrap_or_else(default_lang)),
+    }
+}
+
+// 从名称表中获取对应语言的名称，不存在则返回空字符串
+fn get_name<const N: usize>(names: &[(&str, [&str; N])], code: &str, lang: Lang) -> String {
+    names
+        .binary_search_by_key(&code, |(value, _)| value)
+        .map(|index| names[index].1[lang.0].to_string())
+        .unwrap_or_default()
+}
+
+// 国家(地区)的名称
+pub fn get_country_name(code: &str, lang: Lang) -> String {
+    get_name(&COUNTRY_NAMES, code, lang)
+}
+
+// 省与市的本地化名称，从csv文件中读取，格式为: lang,name,localized
+// 如: zh-CN,Guangdong,广东
+static LOCATION_NAMES: LazyLock<HashMap<(Lang, String), String>> = LazyLock::new(|| {
+    let mut names = HashMap::new();
+    let Some(file) = config::get_env("LOCATION_NAMES_FILE") else {
+        return names;
+    };
+    let data = match fs::read(&file) {
+        Ok(data) => data,
+        Err(err) => {
+            error!(category = "i18n", file, "load location names fail: {err}");
+            return names;
+        }
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(data.as_slice());
+    for record in reader.records().flatten() {
+        let (Some(lang), Some(name), Some(localized)) = (
+            record.get(0).and_then(Lang::from_name),
+            record.get(1),
+            record.get(2),
+        ) else {
+            continue;
+        };
+        names.insert(
+            (lang, name.trim().to_string()),
+            localized.trim().to_string(),
+        );
+    }
+    names
+});
+
+fn get_location_name(name: &str, lang: Lang) -> Option<String> {
+    if name.is_empty() {
+        return None;
+    }
+    LOCATION_NAMES.get(&(lang, name.to_string())).cloned()
+}
+
+// 设置国家、大洲与区域名称，以及本地化省与市的名称(若有配置)
+pub fn localize(location: &mut Location, lang: Lang) {
+    location.country_name = get_country_name(&location.country, lang);
+    location.continent_name = get_name(&CONTINENT_NAMES, &location.continent, lang);
+    location.region_name = get_name(&REGION_NAMES, &location.region, lang);
+    location.subregion_name = get_name(&REGION_NAMES, &location.subregion, lang);
+    if let Some(province) = get_location_name(&location.province, lang) {
+        location.province = province;
+    }
+    if let Some(city) = get_location_name(&location.city, lang) {
+        location.city = city;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn lang(name: &str) -> Lang {
+        Lang::from_name(name).unwrap()
+    }
+
+    fn new_headers(accept_language: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_str(accept_language).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn lang_from_name() {
+        for (name, expected) in [
+            ("en", "en"),
+            ("EN-us", "en"),
+            ("zh", "zh-CN"),
+            ("zh_CN", "zh-CN"),
+            ("zh-Hans", "zh-CN"),
+            ("zh-SG", "zh-CN"),
+            ("zh-Hant", "zh-TW"),
+            ("zh_HK", "zh-TW"),
+            ("zh-tw", "zh-TW"),
+            ("pt-BR", "pt"),
+            ("de-AT", "de"),
+            (" ja ", "ja"),
+        ] {
+            assert_eq!(expected, lang(name).as_str(), "{name}");
+        }
+        for name in ["", "xx", "it-IT"] {
+            assert!(Lang::from_name(name).is_none(), "{name}");
+        }
+    }
+
+    #[test]
+    fn negotiate_lang() {
+        assert_eq!(None, negotiate(&HeaderMap::new()));
+        // q值高的优先，相同时按出现的顺序，不支持的语言忽略
+        for (accept_language, expected) in [
+            ("fr;q=0.5, de;q=0.8", Some("de")),
+            ("xx, ja;q=0.1", Some("ja")),
+            ("ko, ru", Some("ko")),
+            ("pt-BR;q=0.9, es;q=0.9", Some("pt")),
+            ("zh-Hant;q=0, en;q=0.3", Some("en")),
+            ("zh-Hant;q=0", None),
+            ("it, xx", None),
+        ] {
+            assert_eq!(
+                expected,
+                negotiate(&new_headers(accept_language)).map(|lang| lang.as_str()),
+                "{accept_language}"
+            );
+        }
+    }
+
+    #[test]
+    fn get_lang_from_query() {
+        let headers = new_headers("ja");
+        assert_eq!("ja", get_lang(None, &headers).unwrap().as_str());
+        assert_eq!("ja", get_lang(Some("lang="), &headers).unwrap().as_str());
+        assert_eq!(
+            "zh-TW",
+            get_lang(Some("format=json&lang=zh_HK"), &headers)
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(default_lang(), get_lang(None, &new_headers("it")).unwrap());
+
+        let err = get_lang(Some("lang=xx"), &headers).unwrap_err();
+        assert_eq!(400, err.status);
+        assert_eq!("lang", err.category);
+        assert_eq!("Lang xx is not supported", err.message);
+    }
+
+    #[test]
+    fn sorted_names() {
+        // 使用二分查找，因此名称表需按代码排序
+        for codes in [
+            COUNTRY_NAMES.map(|(code, _)| code).to_vec(),
+            CONTINENT_NAMES.map(|(code, _)| code).to_vec(),
+            REGION_NAMES.map(|(code, _)| code).to_vec(),
+        ] {
+            assert!(codes.windows(2).all(|arr| arr[0] < arr[1]), "{codes:?}");
+            for (index, code) in codes.iter().enumerate() {
+                assert_eq!(Ok(index), codes.binary_search(code));
+            }
+        }
+    }
+
+    #[test]
+    fn localize_names() {
+        assert_eq!("China", get_country_name("CN", lang("en")));
+        assert_eq!("中国", get_country_name("CN", lang("zh-CN")));
+        assert_eq!("Deutschland", get_country_name("DE", lang("de")));
+        assert_eq!("Allemagne", get_country_name("DE", lang("fr")));
+        assert_eq!("", get_country_name("ZZ", lang("en")));
+
+        let mut location = Location {
+            country: "CN".to_string(),
+            continent: "AS".to_string(),
+            region: "142".to_string(),
+            subregion: "030".to_string(),
+            ..Default::default()
+        };
+        localize(&mut location, lang("zh-TW"));
+        assert_eq!("中國", location.country_name);
+        assert_eq!("亞洲", location.continent_name);
+        assert_eq!("亞洲", location.region_name);
+        assert_eq!("東亞", location.subregion_name);
+        localize(&mut location, lang("en"));
+        assert_eq!("China", location.country_name);
+        assert_eq!("Asia", location.continent_name);
+        assert_eq!("Eastern Asia", location.subregion_name);
+    }
+}
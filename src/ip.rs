@@ -1,14 +1,50 @@
+use crate::config;
+use crate::country;
 use crate::ip_data;
+use crate::metrics;
 use serde::{Deserialize, Serialize};
-use std::net::{AddrParseError, Ipv4Addr, Ipv6Addr};
-use std::str::FromStr;
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+use tracing::{field, instrument, Span};
+use utoipa::ToSchema;
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+// 位置信息的精确程度
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Confidence {
+    // 无数据
+    #[default]
+    None,
+    Country,
+    Province,
+    City,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Location {
-    ip: String,
-    country: String,
-    province: String,
-    city: String,
+    pub ip: String,
+    pub country: String,
+    // 国家(地区)名称，根据请求的语言设置
+    pub country_name: String,
+    pub province: String,
+    pub city: String,
+    // 是否有对应的数据，ip在数据集的空白区间时为false
+    pub found: bool,
+    pub confidence: Confidence,
+    // 大洲代码与名称
+    pub continent: String,
+    pub continent_name: String,
+    // 联合国M49的区域与子区域
+    pub region: String,
+    pub region_name: String,
+    pub subregion: String,
+    pub subregion_name: String,
+    // 是否欧盟与欧洲经济区
+    pub eu: bool,
+    pub eea: bool,
+    pub calling_code: String,
+    pub currency: String,
 }
 fn get_country(index: usize) -> String {
     if let Some(value) = ip_data::COUNTRY_LIST.get(index) {
@@ -30,33 +66,229 @@ fn get_city(index: usize) -> String {
 }
 
 fn get_location_info(data: Option<&[usize; 3]>) -> Location {
-    if let Some(value) = data {
-        return Location {
-            country: get_country(value[0]),
-            province: get_province(value[1]),
-            city: get_city(value[2]),
-            ..Default::default()
-        };
+    let Some(value) = data else {
+        return Location::default();
+    };
+    let mut location = Location {
+        country: get_country(value[0]),
+        province: get_province(value[1]),
+        city: get_city(value[2]),
+        ..Default::default()
+    };
+    location.confidence = if !location.city.is_empty() {
+        Confidence::City
+    } else if !location.province.is_empty() {
+        Confidence::Province
+    } else if !location.country.is_empty() {
+        Confidence::Country
+    } else {
+        Confidence::None
+    };
+    location.found = location.confidence != Confidence::None;
+    location
+}
+
+// 只接受标准格式的ip，不做兼容处理
+static IP_PARSE_STRICT: LazyLock<bool> =
+    LazyLock::new(|| config::get_env_bool("IP_PARSE_STRICT", false));
+
+// ipv6的zone id，如fe80::1%eth0
+fn parse_ipv6_with_zone(value: &str) -> Option<IpAddr> {
+    let host = match value.split_once('%') {
+        Some((host, zone)) if !zone.is_empty() => host,
+        Some(_) => return None,
+        None => value,
+    };
+    host.parse::<Ipv6Addr>().ok().map(IpAddr::V6)
+}
+
+// 兼容各种常见的ip写法
+fn parse_ip_lenient(value: &str) -> Option<IpAddr> {
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    // [2001:db8::1]:443 或 [2001:db8::1]，也兼容[1.2.3.4]
+    if let Some(rest) = value.strip_prefix('[') {
+        let (host, tail) = rest.split_once(']')?;
+        if !tail.is_empty() && tail.strip_prefix(':')?.parse::<u16>().is_err() {
+            return None;
+        }
+        return parse_ipv6_with_zone(host)
+            .or_else(|| host.parse::<Ipv4Addr>().ok().map(IpAddr::V4));
+    }
+    if value.contains('%') {
+        return parse_ipv6_with_zone(value);
+    }
+    // 1.2.3.4:8080
+    if let Some((host, port)) = value.rsplit_once(':') {
+        if port.parse::<u16>().is_ok() {
+            if let Ok(ip) = host.parse::<Ipv4Addr>() {
+                return Some(IpAddr::V4(ip));
+            }
+        }
     }
-    Location::default()
+    // 十六进制，8位以内为ipv4，否则为ipv6
+    if let Some(hex) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        if hex.is_empty() || hex.len() > 32 {
+            return None;
+        }
+        let value = u128::from_str_radix(hex, 16).ok()?;
+        return Some(if hex.len() <= 8 {
+            IpAddr::V4(Ipv4Addr::from(value as u32))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(value))
+        });
+    }
+    // 十进制整数，如16909060
+    if value.chars().all(|c| c.is_ascii_digit()) {
+        return value
+            .parse::<u32>()
+            .ok()
+            .map(|value| IpAddr::V4(value.into()));
+    }
+    None
+}
+
+// 解析ip，是否严格模式由IP_PARSE_STRICT配置
+pub fn parse_ip(value: &str) -> Result<IpAddr, AddrParseError> {
+    parse_ip_with(value, *IP_PARSE_STRICT)
 }
 
+// 非严格模式下支持括号、端口、zone id、整数等写法，
+// ipv4映射的ipv6地址转换为ipv4
+fn parse_ip_with(value: &str, strict: bool) -> Result<IpAddr, AddrParseError> {
+    if strict {
+        return value.parse::<IpAddr>();
+    }
+    let value = value.trim();
+    match parse_ip_lenient(value) {
+        Some(IpAddr::V6(ip)) => Ok(ip
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(IpAddr::V6(ip))),
+        Some(ip) => Ok(ip),
+        // 无法解析时使用标准的解析返回出错信息
+        None => value.parse::<IpAddr>(),
+    }
+}
+
+// 查询ip对应的位置信息（不记录指标），返回的ip为标准格式
+// 访问控制、日志等内部的查询使用此函数
+pub fn lookup(ip: &str) -> Result<Location, AddrParseError> {
+    let addr = parse_ip(ip)?;
+    let mut result = match addr {
+        IpAddr::V6(addr) => {
+            let value: u128 = addr.into();
+            let index = ip_data::IPV6_LIST
+                .binary_search(&value)
+                .unwrap_or_else(|index| index);
+            get_location_info(ip_data::IPV6_LOCATION_LIST.get(index))
+        }
+        IpAddr::V4(addr) => {
+            let value: u32 = addr.into();
+            let index = ip_data::IPV4_LIST
+                .binary_search(&value)
+                .unwrap_or_else(|index| index);
+            get_location_info(ip_data::IPV4_LOCATION_LIST.get(index))
+        }
+    };
+    result.ip = addr.to_string();
+    country::enrich(&mut result);
+    Ok(result)
+}
+
+// 对外提供的查询(http与grpc)使用，记录查询指标
+#[instrument(name = "lookup", fields(country = field::Empty))]
 pub fn get_location(ip: &str) -> Result<Location, AddrParseError> {
-    let mut result = if ip.contains(':') {
-        let addr = Ipv6Addr::from_str(ip)?;
-        let value: u128 = addr.into();
-        let index = ip_data::IPV6_LIST
-            .binary_search(&value)
-            .unwrap_or_else(|index| index);
-        get_location_info(ip_data::IPV6_LOCATION_LIST.get(index))
+    let result = lookup(ip)?;
+    Span::current().record("country", result.country.as_str());
+    let version = if result.ip.contains(':') {
+        "ipv6"
     } else {
-        let addr = Ipv4Addr::from_str(ip)?;
-        let value: u32 = addr.into();
-        let index = ip_data::IPV4_LIST
-            .binary_search(&value)
-            .unwrap_or_else(|index| index);
-        get_location_info(ip_data::IPV4_LOCATION_LIST.get(index))
+        "ipv4"
     };
-    result.ip = ip.to_string();
+    metrics::observe_lookup(version, &result.country);
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_unspecified_ips() {
+        for value in [
+            "0.0.0.0",
+            "[0.0.0.0]",
+            "0.0.0.0:80",
+            "0",
+            "::",
+            "[::]",
+            " 0.0.0.0 ",
+        ] {
+            assert!(parse_ip(value).unwrap().is_unspecified(), "{value}");
+        }
+        assert!(!parse_ip("1.0.1.1").unwrap().is_unspecified());
+    }
+
+    #[test]
+    fn parse_ip_inputs() {
+        for (value, expected) in [
+            ("[2001:db8::1]:443", "2001:db8::1"),
+            ("[2001:db8::1]", "2001:db8::1"),
+            ("fe80::1%eth0", "fe80::1"),
+            ("[fe80::1%eth0]:8080", "fe80::1"),
+            ("1.2.3.4:8080", "1.2.3.4"),
+            ("[1.2.3.4]", "1.2.3.4"),
+            ("16909060", "1.2.3.4"),
+            ("0x01020304", "1.2.3.4"),
+            ("::ffff:1.2.3.4", "1.2.3.4"),
+            (" 1.2.3.4 ", "1.2.3.4"),
+        ] {
+            assert_eq!(expected, lookup(value).unwrap().ip, "{value}");
+        }
+        for value in [
+            "abc",
+            "[2001:db8::1]:abc",
+            "fe80::1%",
+            "1.2.3.4:",
+            "[1.2.3.4",
+        ] {
+            assert!(parse_ip_with(value, false).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_ip_strictly() {
+        for value in [
+            "[2001:db8::1]:443",
+            "fe80::1%eth0",
+            "1.2.3.4:8080",
+            "16909060",
+            "0x01020304",
+            " 1.2.3.4",
+        ] {
+            assert!(parse_ip_with(value, true).is_err(), "{value}");
+        }
+        assert_eq!(
+            "2001:db8::1",
+            parse_ip_with("2001:db8::1", true).unwrap().to_string()
+        );
+        // 严格模式下不转换ipv4映射的ipv6地址
+        assert_eq!(
+            "::ffff:1.2.3.4",
+            parse_ip_with("::ffff:1.2.3.4", true).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn lookup_canonical_ip() {
+        let location = lookup("::ffff:1.0.1.1").unwrap();
+        assert_eq!("1.0.1.1", location.ip);
+        assert!(location.found);
+        assert!(lookup("abc").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+pub static GENERATED_AT: i64 = 1738368000;
+
 pub static COUNTRY_LIST: [&str; 12] = [
     "", "", "AU", "CN", "JP", "TH", "CH", "US", "BR", "SG", "KR", "TW",
 ];
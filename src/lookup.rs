@@ -0,0 +1,47 @@
+use crate::config;
+use crate::error::{HTTPError, HTTPResult};
+use crate::i18n::{self, Lang};
+use crate::ip::{self, Location};
+use crate::resolver;
+
+// http与grpc共用的查询，保证两者的校验与结果一致
+
+// 未指定时是否使用strict模式
+pub fn default_strict() -> bool {
+    config::get_env_bool("LOOKUP_STRICT", false)
+}
+
+// 查询单个ip，strict模式下无位置数据返回404
+pub fn lookup_ip(ip: &str, strict: bool, lang: Lang) -> HTTPResult<Location> {
+    let mut location = ip::get_location(ip.trim())?;
+    if strict && !location.found {
+        return Err(HTTPError::new_with_category_status(
+            &format!("No location data for {}", location.ip),
+            "locationNotFound",
+            404,
+        ));
+    }
+    i18n::localize(&mut location, lang);
+    Ok(location)
+}
+
+// 批量查询，数量不能超过BATCH_MAX_SIZE，总是返回found标记而不使用strict模式
+pub fn lookup_ips(ips: &[String], lang: Lang) -> HTTPResult<Vec<Location>> {
+    let max = config::get_env_number("BATCH_MAX_SIZE", 100_usize);
+    if ips.len() > max {
+        return Err(HTTPError::new_with_category(
+            &format!("The count of ips should be less than or equal to {max}"),
+            "batchTooLarge",
+        ));
+    }
+    ips.iter().map(|ip| lookup_ip(ip, false, lang)).collect()
+}
+
+// 查询域名所有ip的位置
+pub async fn lookup_host(host: &str, strict: bool, lang: Lang) -> HTTPResult<Vec<Location>> {
+    let mut locations = resolver::lookup_host(host.trim(), strict).await?;
+    for location in locations.iter_mut() {
+        i18n::localize(location, lang);
+    }
+    Ok(locations)
+}
@@ -1,55 +1,243 @@
-use axum::http::Uri;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap, HeaderValue, Method, StatusCode, Uri};
 use axum::{
-    error_handling::HandleErrorLayer, extract::Path, middleware::from_fn, routing::get, Json,
-    Router,
+    error_handling::HandleErrorLayer,
+    extract::{rejection::JsonRejection, Path},
+    middleware::{from_fn, from_fn_with_state},
+    response::{IntoResponse, Response},
+    routing::{any, get, post},
+    Extension, Json, Router,
 };
 use axum_client_ip::InsecureClientIp;
-use error::HTTPResult;
+use error::{HTTPError, HTTPResult};
+use opentelemetry_sdk::trace::SdkTracerProvider;
 use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
-use std::{env, str::FromStr};
 use tokio::signal;
+use tokio::sync::watch;
 use tower::ServiceBuilder;
-use tracing::info;
 use tracing::Level;
-use tracing_subscriber::FmtSubscriber;
+use tracing::{error, info};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::{filter_fn, LevelFilter};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
 
+mod cache;
+mod compression;
+mod config;
+mod context;
+mod country;
+mod country_data;
 mod dist;
 mod error;
+mod format;
+mod forward_auth;
 mod gen;
+mod grpc;
+mod health;
+mod i18n;
 mod ip;
 mod ip_data;
+mod lookup;
+mod metrics;
 mod middleware;
+mod openapi;
+mod proxy;
+mod resolver;
+mod telemetry;
+mod tls;
 
-fn init_logger() {
-    let mut level = Level::INFO;
-    if let Ok(log_level) = env::var("LOG_LEVEL") {
-        if let Ok(value) = Level::from_str(log_level.as_str()) {
-            level = value;
-        }
-    }
+// 日志相关需要在退出时处理的资源
+struct LoggerGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+    _access_log_guard: Option<WorkerGuard>,
+}
 
+fn new_fmt_layer<S, W>(json: bool, ansi: bool, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
     let timer = tracing_subscriber::fmt::time::OffsetTime::local_rfc_3339().unwrap_or_else(|_| {
         tracing_subscriber::fmt::time::OffsetTime::new(
             time::UtcOffset::from_hms(0, 0, 0).unwrap(),
             time::format_description::well_known::Rfc3339,
         )
     });
-
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(level)
+    let layer = tracing_subscriber::fmt::layer()
         .with_timer(timer)
-        .finish();
+        .with_ansi(ansi)
+        .with_writer(writer);
+    if json {
+        layer.json().boxed()
+    } else {
+        layer.boxed()
+    }
+}
+
+// 访问日志输出的文件，按ACCESS_LOG_ROTATION(daily/hourly/minutely/never)滚动
+fn new_access_log_writer(file: &str) -> Result<RollingFileAppender, String> {
+    let path = std::path::Path::new(file);
+    let dir = path
+        .parent()
+        .filter(|value| !value.as_os_str().is_empty())
+        .unwrap_or(std::path::Path::new("."));
+    let prefix = path
+        .file_name()
+        .map(|value| value.to_string_lossy().to_string())
+        .unwrap_or_else(|| "access.log".to_string());
+    let rotation = match config::get_env("ACCESS_LOG_ROTATION")
+        .unwrap_or_default()
+        .as_str()
+    {
+        "minutely" => Rotation::MINUTELY,
+        "hourly" => Rotation::HOURLY,
+        "never" => Rotation::NEVER,
+        _ => Rotation::DAILY,
+    };
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(prefix);
+    let max_files = config::get_env_number("ACCESS_LOG_MAX_FILES", 0_usize);
+    if max_files > 0 {
+        builder = builder.max_log_files(max_files);
+    }
+    builder.build(dir).map_err(|err| err.to_string())
+}
+
+fn init_logger() -> LoggerGuard {
+    let level = config::get_env("LOG_LEVEL")
+        .and_then(|value| Level::from_str(&value).ok())
+        .unwrap_or(Level::INFO);
+
+    // LOG_FORMAT=json 则输出json格式日志
+    let json = config::get_env("LOG_FORMAT").is_some_and(|value| value == "json");
+    let mut layers = vec![];
+
+    // ACCESS_LOG_FILE 设置则访问日志输出至单独的文件
+    let mut access_log_guard = None;
+    match config::get_env("ACCESS_LOG_FILE").map(|file| new_access_log_writer(&file)) {
+        Some(Ok(writer)) => {
+            let (writer, guard) = tracing_appender::non_blocking(writer);
+            access_log_guard = Some(guard);
+            layers.push(
+                new_fmt_layer(json, false, writer)
+                    .with_filter(filter_fn(|metadata| {
+                        metadata.target() == middleware::ACCESS_LOG_TARGET
+                    }))
+                    .boxed(),
+            );
+            layers.push(
+                new_fmt_layer(json, true, std::io::stdout)
+                    .with_filter(filter_fn(|metadata| {
+                        metadata.target() != middleware::ACCESS_LOG_TARGET
+                    }))
+                    .boxed(),
+            );
+        }
+        result => {
+            if let Some(Err(err)) = result {
+                eprintln!("init access log file fail: {err}");
+            }
+            layers.push(new_fmt_layer(json, true, std::io::stdout));
+        }
+    }
+
+    telemetry::init_propagator();
+    let mut tracer_provider = None;
+    if telemetry::is_enabled() {
+        match telemetry::new_tracer_provider() {
+            Ok(provider) => tracer_provider = Some(provider),
+            Err(err) => eprintln!("init otlp exporter fail: {err}"),
+        }
+    }
+    if let Some(provider) = &tracer_provider {
+        layers.push(
+            tracing_opentelemetry::layer()
+                .with_tracer(telemetry::new_tracer(provider))
+                .boxed(),
+        );
+    }
+
+    let subscriber = tracing_subscriber::registry()
+        .with(layers)
+        .with(LevelFilter::from_level(level));
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+    LoggerGuard {
+        tracer_provider,
+        _access_log_guard: access_log_guard,
+    }
+}
+
+// api相关的路由，认证、限流等仅针对api
+fn new_api_router() -> Router {
+    let scope = |scope: middleware::Scope| from_fn_with_state(scope, middleware::require_scope);
+    let router = Router::new()
+        .route(
+            "/ip-locations",
+            post(get_locations).route_layer(scope(middleware::Scope::Batch)),
+        )
+        .route(
+            "/ip-locations/{ip}",
+            get(get_location).route_layer(scope(middleware::Scope::Lookup)),
+        )
+        .route(
+            "/host-locations/{host}",
+            get(get_host_locations).route_layer(scope(middleware::Scope::Lookup)),
+        )
+        .route(
+            "/admin/api-keys",
+            get(get_api_key_usages).route_layer(scope(middleware::Scope::Admin)),
+        )
+        .route("/errors", get(get_error_codes))
+        .route("/openapi.json", get(openapi::get_openapi))
+        .route("/docs", get(openapi::get_docs))
+        .route("/docs/redoc.standalone.js", get(openapi::get_redoc_script))
+        .fallback(api_not_found)
+        .layer(compression::new_compression_layer())
+        // 后面的layer先执行
+        .layer(from_fn(middleware::rate_limit))
+        .layer(from_fn(middleware::authenticate))
+        // 认证失败次数过多的ip在认证前拒绝
+        .layer(from_fn(middleware::limit_auth_failures))
+        // 认证等出错时也需要按请求的格式输出
+        .layer(from_fn(middleware::response_format));
+    // cors需要最先处理，preflight请求无需认证
+    match middleware::new_cors_layer() {
+        Some(cors) => router.layer(cors),
+        None => router,
+    }
 }
 
 #[tokio::main]
 async fn run() {
-    let app = Router::new()
+    let mut app = Router::new()
         .route("/ping", get(ping))
-        .route("/api/ip-locations/{ip}", get(get_location))
-        .fallback(get(serve))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(get_metrics))
+        .nest("/api", new_api_router());
+    // 设置了反向代理则其它请求均转发至上游
+    app = if proxy::is_enabled() {
+        app.fallback(proxy::proxy)
+    } else {
+        app.fallback(get(serve))
+    };
+    let app = app
+        .layer(middleware::GeoAccessLayer::new(
+            middleware::GeoPolicy::from_env(),
+        ))
+        // forward auth自行根据策略判断是否允许访问
+        // 因此在地区访问控制之后添加
+        .route("/forward-auth", any(forward_auth::forward_auth))
+        .route("/forward-auth/{*path}", any(forward_auth::forward_auth))
         .layer(
             ServiceBuilder::new()
                 .layer(HandleErrorLayer::new(error::handle_error))
@@ -59,7 +247,67 @@ async fn run() {
         .layer(from_fn(middleware::access_log))
         .layer(from_fn(middleware::entry));
 
+    // 只监听一次退出信号，由http(s)与grpc服务共用
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        let _ = shutdown_tx.send(true);
+    });
+    let shutdown = move || wait_shutdown(shutdown_rx.clone());
+
+    // grpc服务使用单独的端口
+    if let Some(grpc_addr) = grpc::get_addr() {
+        tokio::spawn(grpc::serve(
+            grpc_addr,
+            health::wait_shutting_down(),
+            shutdown(),
+        ));
+    }
+
     let addr = "0.0.0.0:7001";
+    // 配置了证书则使用https
+    if let Some(tls_config) = tls::TlsConfig::from_env() {
+        let rustls_config = match tls_config.new_rustls_config().await {
+            Ok(value) => value,
+            Err(err) => {
+                error!(category = "tls", "load certificate fail: {err}");
+                std::process::exit(1);
+            }
+        };
+        let handle = axum_server::Handle::new();
+        tokio::spawn({
+            let handle = handle.clone();
+            let shutdown = shutdown();
+            async move {
+                shutdown.await;
+                handle.graceful_shutdown(Some(Duration::from_secs(30)));
+            }
+        });
+        let redirect_addr = tls_config.redirect_addr;
+        let redirect_port = tls_config.redirect_port;
+        tokio::spawn(tls::watch_certificates(tls_config, rustls_config.clone()));
+
+        let redirect = {
+            let handle = handle.clone();
+            async move {
+                match redirect_addr {
+                    Some(addr) => tls::serve_redirect(addr, redirect_port, handle).await,
+                    None => Ok(()),
+                }
+            }
+        };
+        info!("listening on https://{addr}/");
+        let https = axum_server::bind_rustls(SocketAddr::from_str(addr).unwrap(), rustls_config)
+            .handle(handle)
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>());
+        // 任一服务失败(如端口被占用)则退出
+        if let Err(err) = tokio::try_join!(https, redirect) {
+            error!(category = "tls", "serve fail: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     info!("listening on http://{addr}/");
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
 
@@ -67,10 +315,16 @@ async fn run() {
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
+    .with_graceful_shutdown(shutdown())
     .await
     .unwrap();
 }
 
+// 等待退出信号，收到时readyz的摘除等待已完成
+async fn wait_shutdown(mut rx: watch::Receiver<bool>) {
+    let _ = rx.wait_for(|value| *value).await;
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         signal::ctrl_c()
@@ -94,34 +348,274 @@ async fn shutdown_signal() {
         _ = terminate => {},
     }
 
-    info!("signal received, starting graceful shutdown");
+    // 先将readyz设置为失败，等待负载均衡摘除后再关闭
+    health::set_shutting_down();
+    let drain = config::get_env_number("SHUTDOWN_DRAIN_SECONDS", 5_u64);
+    info!("signal received, draining for {drain}s before graceful shutdown");
+    tokio::time::sleep(Duration::from_secs(drain)).await;
+    info!("starting graceful shutdown");
 }
 
+// 严格模式下无数据的ip返回404，?strict=优先于LOOKUP_STRICT
+fn is_strict_lookup(query: Option<&str>) -> bool {
+    let value = query.and_then(|query| {
+        query.split('&').find_map(|item| {
+            let (key, value) = item.split_once('=')?;
+            (key == "strict").then_some(value)
+        })
+    });
+    match value {
+        Some(value) => matches!(value, "true" | "1"),
+        None => lookup::default_strict(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/ip-locations/{ip}",
+    tag = "location",
+    params(
+        ("ip" = String, Path, description = "Ip, 0.0.0.0 or :: means the client ip"),
+        ("format" = Option<String>, Query, description = "Response format: json, text, csv, msgpack, cbor, xml"),
+        ("fields" = Option<String>, Query, description = "Comma separated fields to respond"),
+        ("naming" = Option<String>, Query, description = "Field naming: camelCase or snake_case"),
+        ("compact" = Option<bool>, Query, description = "Respond the fields and values separately"),
+        ("lang" = Option<String>, Query, description = "Language of the names, Accept-Language is used if not set"),
+        ("strict" = Option<bool>, Query, description = "Respond 404 if the ip has no location data"),
+    ),
+    responses(
+        (status = 200, description = "Location of the ip", body = ip::Location),
+        (status = 304, description = "Not modified"),
+        (status = 400, description = "Invalid ip", body = HTTPError),
+        (status = 404, description = "No location data in strict mode", body = HTTPError),
+    ),
+    security((), ("apiKey" = []), ("apiKeyQuery" = []))
+)]
 async fn get_location(
     InsecureClientIp(client_ip): InsecureClientIp,
+    Extension(format): Extension<format::Format>,
+    Extension(shape): Extension<format::Shape>,
+    Extension(lang): Extension<i18n::Lang>,
     Path(ip): Path<String>,
-) -> HTTPResult<Json<ip::Location>> {
-    // TODO 判断是否内网
-    // 0.0.0.0
-    let value = if ip == "0.0.0.0" {
+    parts: Parts,
+) -> HTTPResult<Response> {
+    let strict = is_strict_lookup(parts.uri.query());
+    // 先校验ip，避免非法ip因If-None-Match而返回304
+    let addr = ip::parse_ip(&ip)?;
+    // 0.0.0.0与::(包括[::]、0.0.0.0:80、0等写法)表示查询客户端自身ip
+    let self_lookup = addr.is_unspecified();
+    let value = if self_lookup {
         client_ip.to_string()
     } else {
-        ip
+        addr.to_string()
     };
-    let data = ip::get_location(&value)?;
-    Ok(Json(data))
+    // 数据集不变则结果不变，因此etag由数据集版本、ip、响应格式与语言生成
+    let etag = cache::new_lookup_etag(&[
+        &value,
+        format.as_str(),
+        &shape.cache_key(),
+        lang.as_str(),
+        if strict { "strict" } else { "" },
+    ]);
+    let cache_headers = [
+        (header::ETAG, HeaderValue::from_str(&etag)?),
+        (
+            header::CACHE_CONTROL,
+            cache::lookup_cache_control(
+                self_lookup || parts.extensions.get::<Arc<middleware::ApiKey>>().is_some(),
+            ),
+        ),
+    ];
+    // 先查询再判断etag，strict模式下无位置数据时返回404而非304
+    let data = lookup::lookup_ip(&value, strict, lang)?;
+    if cache::is_not_modified(&parts.headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    Ok((
+        cache_headers,
+        format::Formatted {
+            format,
+            shape,
+            root: "location",
+            content: format::Content::One(data),
+        },
+    )
+        .into_response())
+}
+
+// 解析域名并查询所有ip的位置信息
+#[utoipa::path(
+    get,
+    path = "/api/host-locations/{host}",
+    tag = "location",
+    params(
+        ("host" = String, Path, description = "Hostname, the A and AAAA records are resolved"),
+        ("format" = Option<String>, Query, description = "Response format: json, text, csv, msgpack, cbor, xml"),
+        ("fields" = Option<String>, Query, description = "Comma separated fields to respond"),
+        ("naming" = Option<String>, Query, description = "Field naming: camelCase or snake_case"),
+        ("compact" = Option<bool>, Query, description = "Respond the fields and values separately"),
+        ("lang" = Option<String>, Query, description = "Language of the names, Accept-Language is used if not set"),
+        ("strict" = Option<bool>, Query, description = "Ignore the addresses without location data, respond 404 if none is left"),
+    ),
+    responses(
+        (status = 200, description = "Locations of the resolved addresses", body = Vec<ip::Location>),
+        (status = 400, description = "Invalid hostname or no address", body = HTTPError),
+        (status = 404, description = "Hostname lookup is not enabled, or no location data in strict mode", body = HTTPError),
+        (status = 500, description = "The resolver can't be initialized", body = HTTPError),
+        (status = 504, description = "Resolve timeout", body = HTTPError),
+    ),
+    security((), ("apiKey" = []), ("apiKeyQuery" = []))
+)]
+async fn get_host_locations(
+    Extension(format): Extension<format::Format>,
+    Extension(shape): Extension<format::Shape>,
+    Extension(lang): Extension<i18n::Lang>,
+    Path(host): Path<String>,
+    uri: Uri,
+) -> HTTPResult<Response> {
+    let strict = is_strict_lookup(uri.query());
+    let result = lookup::lookup_host(&host, strict, lang).await?;
+    // 解析结果会变化，因此不设置etag且不可被共享缓存
+    Ok((
+        [(header::CACHE_CONTROL, cache::lookup_cache_control(true))],
+        format::Formatted {
+            format,
+            shape,
+            root: "locations",
+            content: format::Content::Many(result),
+        },
+    )
+        .into_response())
+}
+
+// 批量查询ip的位置信息
+#[utoipa::path(
+    post,
+    path = "/api/ip-locations",
+    tag = "location",
+    params(
+        ("format" = Option<String>, Query, description = "Response format: json, text, csv, msgpack, cbor, xml"),
+        ("fields" = Option<String>, Query, description = "Comma separated fields to respond"),
+        ("naming" = Option<String>, Query, description = "Field naming: camelCase or snake_case"),
+        ("compact" = Option<bool>, Query, description = "Respond the fields and values separately"),
+        ("lang" = Option<String>, Query, description = "Language of the names, Accept-Language is used if not set"),
+    ),
+    request_body = Vec<String>,
+    responses(
+        (status = 200, description = "Locations of the ips", body = Vec<ip::Location>),
+        (status = 400, description = "Invalid ip or too many ips", body = HTTPError),
+    ),
+    security((), ("apiKey" = []), ("apiKeyQuery" = []))
+)]
+async fn get_locations(
+    Extension(format): Extension<format::Format>,
+    Extension(shape): Extension<format::Shape>,
+    Extension(lang): Extension<i18n::Lang>,
+    payload: Result<Json<Vec<String>>, JsonRejection>,
+) -> HTTPResult<format::Formatted<ip::Location>> {
+    let Json(ips) = payload?;
+    let result = lookup::lookup_ips(&ips, lang)?;
+    Ok(format::Formatted {
+        format,
+        shape,
+        root: "locations",
+        content: format::Content::Many(result),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/api-keys",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Usages of the api keys", body = Vec<middleware::ApiKeyUsage>),
+        (status = 401, description = "Missing or invalid api key", body = HTTPError),
+        (status = 403, description = "The api key has no admin scope", body = HTTPError),
+    ),
+    security(("apiKey" = []), ("apiKeyQuery" = []))
+)]
+async fn get_api_key_usages() -> Json<Vec<middleware::ApiKeyUsage>> {
+    Json(middleware::get_api_key_usages())
 }
 
+// 所有的出错码
+#[utoipa::path(
+    get,
+    path = "/api/errors",
+    tag = "system",
+    responses((status = 200, description = "All error codes", body = Vec<error::ErrorCode>))
+)]
+async fn get_error_codes() -> Json<&'static [error::ErrorCode]> {
+    Json(&error::ERROR_CODES)
+}
+
+async fn api_not_found() -> HTTPError {
+    HTTPError::new_with_category_status("Api not found", "notFound", 404)
+}
+
+#[utoipa::path(
+    get,
+    path = "/ping",
+    tag = "system",
+    responses((status = 200, description = "Service is running", body = String, example = "pong"))
+)]
 async fn ping() -> &'static str {
     "pong"
 }
 
-async fn serve(uri: Uri) -> dist::StaticFile {
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "system",
+    responses((status = 200, description = "The process is alive", body = health::Liveness))
+)]
+async fn healthz() -> Json<health::Liveness> {
+    Json(health::liveness())
+}
+
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    tag = "system",
+    responses(
+        (status = 200, description = "Ready to serve", body = health::Readiness),
+        (status = 503, description = "Not ready or shutting down", body = health::Readiness),
+    )
+)]
+async fn readyz() -> (StatusCode, Json<health::Readiness>) {
+    readyz_status()
+}
+
+fn readyz_status() -> (StatusCode, Json<health::Readiness>) {
+    let readiness = health::readiness();
+    let status = if readiness.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(readiness))
+}
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "Prometheus metrics", body = String, content_type = "text/plain; version=0.0.4"),
+        (status = 500, description = "Failed to encode the metrics", body = HTTPError),
+    )
+)]
+async fn get_metrics() -> HTTPResult<impl IntoResponse> {
+    let data = metrics::encode()?;
+    Ok(([(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)], data))
+}
+
+async fn serve(method: Method, headers: HeaderMap, uri: Uri) -> dist::StaticFile {
     let mut filename = &uri.path()[1..];
     if filename.is_empty() {
         filename = "index.html";
     }
-    dist::get_static_file(filename)
+    dist::get_static_file(filename, method, headers).await
 }
 
 fn main() {
@@ -139,6 +633,93 @@ fn main() {
     // Because we need to get the local offset before Tokio spawns any threads, our `main`
     // function cannot use `tokio::main`.
 
-    init_logger();
+    let logger_guard = init_logger();
+    health::init();
     run();
+    // 退出前将未上报的span发送
+    if let Some(provider) = logger_guard.tracer_provider {
+        if let Err(err) = provider.shutdown() {
+            eprintln!("shutdown tracer provider fail: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readyz_unavailable_when_shutting_down() {
+        let (status, Json(readiness)) = readyz_status();
+        assert_eq!(StatusCode::OK, status);
+        assert!(readiness.ready);
+
+        health::set_shutting_down();
+        let (status, Json(readiness)) = readyz_status();
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, status);
+        assert!(!readiness.ready);
+        assert!(readiness.shutting_down);
+    }
+
+    #[tokio::test]
+    async fn lookup_before_not_modified() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/ip-locations/{ip}", get(get_location))
+            .layer(Extension(format::Format::default()))
+            .layer(Extension(format::Shape::default()))
+            .layer(Extension(i18n::default_lang()));
+        let request = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .header("x-forwarded-for", "1.0.1.1")
+                .header(header::IF_NONE_MATCH, "*")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = app
+            .clone()
+            .oneshot(request("/ip-locations/1.0.1.1?strict=true"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::NOT_MODIFIED, resp.status());
+        assert!(resp.headers().contains_key(header::ETAG));
+
+        // 无位置数据时strict模式返回404
+        let resp = app
+            .clone()
+            .oneshot(request("/ip-locations/192.0.2.1?strict=true"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, resp.status());
+        let resp = app
+            .oneshot(request("/ip-locations/192.0.2.1"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::NOT_MODIFIED, resp.status());
+    }
+
+    #[tokio::test]
+    async fn share_shutdown_signal() {
+        let (tx, rx) = watch::channel(false);
+        let shutdown = move || wait_shutdown(rx.clone());
+        let first = tokio::spawn(shutdown());
+        let second = tokio::spawn(shutdown());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!first.is_finished());
+        tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), async {
+            first.await.unwrap();
+            second.await.unwrap();
+        })
+        .await
+        .unwrap();
+        // 信号后再等待也立即返回
+        tokio::time::timeout(Duration::from_secs(1), shutdown())
+            .await
+            .unwrap();
+    }
 }
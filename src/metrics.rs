@@ -0,0 +1,122 @@
+use crate::ip_data;
+use chrono::Utc;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge, register_int_gauge_vec,
+    Encoder, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder,
+};
+use std::sync::LazyLock;
+
+static HTTP_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "http_requests_total",
+        "Total number of http requests",
+        &["route", "method", "status"]
+    )
+    .unwrap()
+});
+
+static HTTP_REQUEST_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "http_request_duration_seconds",
+        "Http request latency in seconds",
+        &["route", "method", "status"],
+        vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]
+    )
+    .unwrap()
+});
+
+static LOOKUPS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "ip_lookups_total",
+        "Total number of ip lookups by version and result",
+        &["version", "result"]
+    )
+    .unwrap()
+});
+
+static COUNTRY_LOOKUPS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "ip_country_lookups_total",
+        "Total number of ip lookups by country",
+        &["country"]
+    )
+    .unwrap()
+});
+
+static API_KEY_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "api_key_requests_total",
+        "Total number of requests by api key",
+        &["name"]
+    )
+    .unwrap()
+});
+
+static DATASET_RECORDS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register_int_gauge_vec!(
+        "dataset_records",
+        "Number of records of the ip dataset",
+        &["kind"]
+    )
+    .unwrap()
+});
+
+static DATASET_GENERATED_AT: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "dataset_generated_timestamp_seconds",
+        "Unix timestamp when the ip dataset was generated"
+    )
+    .unwrap()
+});
+
+static DATASET_AGE: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!("dataset_age_seconds", "Age of the ip dataset in seconds").unwrap()
+});
+
+// 记录http请求
+pub fn observe_http_request(route: &str, method: &str, status: u16, cost: i64) {
+    let status = status.to_string();
+    let labels = [route, method, status.as_str()];
+    HTTP_REQUESTS_TOTAL.with_label_values(&labels).inc();
+    HTTP_REQUEST_DURATION
+        .with_label_values(&labels)
+        .observe(cost as f64 / 1000.0);
+}
+
+// 记录ip查询，国家为空则认为未命中
+pub fn observe_lookup(version: &str, country: &str) {
+    let result = if country.is_empty() { "miss" } else { "hit" };
+    LOOKUPS_TOTAL.with_label_values(&[version, result]).inc();
+    if !country.is_empty() {
+        COUNTRY_LOOKUPS_TOTAL.with_label_values(&[country]).inc();
+    }
+}
+
+// 记录api key的使用
+pub fn observe_api_key_request(name: &str) {
+    API_KEY_REQUESTS_TOTAL.with_label_values(&[name]).inc();
+}
+
+// 数据集相关指标在获取时更新
+fn update_dataset_metrics() {
+    let records = [
+        ("ipv4", ip_data::IPV4_LIST.len()),
+        ("ipv6", ip_data::IPV6_LIST.len()),
+        ("country", ip_data::COUNTRY_LIST.len()),
+        ("province", ip_data::PROVINCE_LIST.len()),
+        ("city", ip_data::CITY_LIST.len()),
+    ];
+    for (kind, count) in records {
+        DATASET_RECORDS.with_label_values(&[kind]).set(count as i64);
+    }
+    DATASET_GENERATED_AT.set(ip_data::GENERATED_AT);
+    DATASET_AGE.set(Utc::now().timestamp() - ip_data::GENERATED_AT);
+}
+
+// 以prometheus文本格式输出所有指标
+pub fn encode() -> Result<String, prometheus::Error> {
+    update_dataset_metrics();
+    let mut buffer = vec![];
+    TextEncoder::new().encode(&prometheus::gather(), &mut buffer)?;
+    Ok(String::from_utf8_lossy(&buffer).to_string())
+}
@@ -0,0 +1,365 @@
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{HeaderName, Request},
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+use tracing::error;
+use utoipa::ToSchema;
+
+use crate::config;
+use crate::error::{HTTPError, HTTPResult};
+use crate::metrics;
+
+static X_API_KEY: HeaderName = HeaderName::from_static("x-api-key");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Scope {
+    Lookup,
+    Batch,
+    Admin,
+}
+
+impl Scope {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "lookup" => Some(Scope::Lookup),
+            "batch" => Some(Scope::Batch),
+            "admin" => Some(Scope::Admin),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ApiKeyConfig {
+    name: String,
+    key: String,
+    scopes: Vec<Scope>,
+}
+
+// api key对应的身份
+#[derive(Debug)]
+pub struct ApiKey {
+    pub name: String,
+    pub scopes: HashSet<Scope>,
+    // 使用次数
+    requests: AtomicU64,
+    // 最近使用时间
+    last_used_at: AtomicI64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyUsage {
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    pub requests: u64,
+    pub last_used_at: Option<String>,
+}
+
+impl ApiKey {
+    fn new(config: ApiKeyConfig) -> Self {
+        Self {
+            name: config.name,
+            scopes: config.scopes.into_iter().collect(),
+            requests: AtomicU64::new(0),
+            last_used_at: AtomicI64::new(0),
+        }
+    }
+    fn record_usage(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.last_used_at
+            .store(Utc::now().timestamp(), Ordering::Relaxed);
+        metrics::observe_api_key_request(&self.name);
+    }
+    fn usage(&self) -> ApiKeyUsage {
+        let mut scopes: Vec<Scope> = self.scopes.iter().copied().collect();
+        scopes.sort_by_key(|scope| *scope as u8);
+        let last_used_at = self.last_used_at.load(Ordering::Relaxed);
+        ApiKeyUsage {
+            name: self.name.clone(),
+            scopes,
+            requests: self.requests.load(Ordering::Relaxed),
+            last_used_at: chrono::DateTime::from_timestamp(last_used_at, 0)
+                .filter(|_| last_used_at > 0)
+                .map(|value| value.to_rfc3339()),
+        }
+    }
+}
+
+struct ApiKeyStore {
+    keys: HashMap<String, Arc<ApiKey>>,
+    // 未带api key的请求所拥有的权限
+    anonymous_scopes: HashSet<Scope>,
+}
+
+// 从环境变量中读取，格式为: name:key:lookup|batch,name2:key2:admin
+fn parse_api_keys_from_env() -> Vec<ApiKeyConfig> {
+    config::get_env_list("API_KEYS")
+        .iter()
+        .filter_map(|item| {
+            let mut arr = item.splitn(3, ':');
+            let name = arr.next()?.trim().to_string();
+            let key = arr.next()?.trim().to_string();
+            let scopes = arr
+                .next()
+                .unwrap_or_default()
+                .split('|')
+                .filter_map(Scope::from_name)
+                .collect();
+            if key.is_empty() {
+                return None;
+            }
+            Some(ApiKeyConfig { name, key, scopes })
+        })
+        .collect()
+}
+
+// 从json文件中读取，格式为: [{"name": "", "key": "", "scopes": ["lookup"]}]
+fn parse_api_keys_from_file() -> Vec<ApiKeyConfig> {
+    let Some(file) = config::get_env("API_KEYS_FILE") else {
+        return vec![];
+    };
+    let result = fs::read(&file)
+        .map_err(|err| err.to_string())
+        .and_then(|data| {
+            serde_json::from_slice::<Vec<ApiKeyConfig>>(&data).map_err(|err| err.to_string())
+        });
+    match result {
+        Ok(keys) => keys,
+        Err(err) => {
+            error!(category = "apiKey", file, "load api keys fail: {err}");
+            vec![]
+        }
+    }
+}
+
+// 未带api key的请求所拥有的权限，默认为lookup(web ui使用)，设置为none则无任何权限
+fn get_anonymous_scopes() -> HashSet<Scope> {
+    match config::get_env("API_KEY_ANONYMOUS_SCOPES") {
+        Some(value) => value.split(',').filter_map(Scope::from_name).collect(),
+        None => HashSet::from([Scope::Lookup]),
+    }
+}
+
+static API_KEY_STORE: LazyLock<ApiKeyStore> = LazyLock::new(|| {
+    let mut keys = HashMap::new();
+    for item in parse_api_keys_from_file()
+        .into_iter()
+        .chain(parse_api_keys_from_env())
+    {
+        keys.insert(item.key.clone(), Arc::new(ApiKey::new(item)));
+    }
+    ApiKeyStore {
+        keys,
+        anonymous_scopes: get_anonymous_scopes(),
+    }
+});
+
+impl ApiKeyStore {
+    fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+}
+
+// 从请求头X-API-Key或query中的api_key获取，query中的值需要解码
+fn get_api_key(req: &Request<Body>) -> Option<String> {
+    if let Some(value) = req
+        .headers()
+        .get(&X_API_KEY)
+        .and_then(|value| value.to_str().ok())
+    {
+        return Some(value.to_string());
+    }
+    let Query(mut query) = Query::<HashMap<String, String>>::try_from_uri(req.uri()).ok()?;
+    query.remove("api_key").filter(|value| !value.is_empty())
+}
+
+// 所有api key的使用情况
+pub fn get_api_key_usages() -> Vec<ApiKeyUsage> {
+    let mut usages: Vec<ApiKeyUsage> = API_KEY_STORE
+        .keys
+        .values()
+        .map(|item| item.usage())
+        .collect();
+    usages.sort_by(|a, b| a.name.cmp(&b.name));
+    usages
+}
+
+// 校验api key，成功则将其身份(Arc<ApiKey>)添加至request extensions
+pub async fn authenticate(mut req: Request<Body>, next: Next) -> HTTPResult<Response> {
+    let store = &API_KEY_STORE;
+    if !store.is_enabled() {
+        return Ok(next.run(req).await);
+    }
+    if let Some(key) = get_api_key(&req) {
+        let Some(api_key) = store.keys.get(&key) else {
+            return Err(HTTPError::new_with_category_status(
+                "Api key is invalid",
+                "unauthorized",
+                401,
+            ));
+        };
+        api_key.record_usage();
+        req.extensions_mut().insert(api_key.clone());
+    }
+    Ok(next.run(req).await)
+}
+
+// 校验api key是否有对应的权限，未带api key时按匿名权限校验
+fn check_scope(
+    api_key: Option<&ApiKey>,
+    anonymous_scopes: &HashSet<Scope>,
+    scope: Scope,
+) -> HTTPResult<()> {
+    match api_key {
+        Some(api_key) => {
+            if !api_key.scopes.contains(&scope) {
+                return Err(HTTPError::new_with_category_status(
+                    "Api key does not have the permission",
+                    "forbidden",
+                    403,
+                ));
+            }
+        }
+        None => {
+            if !anonymous_scopes.contains(&scope) {
+                return Err(HTTPError::new_with_category_status(
+                    "Api key is required",
+                    "unauthorized",
+                    401,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// 校验是否有对应的权限，未启用api key时则不校验
+pub async fn require_scope(
+    State(scope): State<Scope>,
+    req: Request<Body>,
+    next: Next,
+) -> HTTPResult<Response> {
+    if !API_KEY_STORE.is_enabled() {
+        return Ok(next.run(req).await);
+    }
+    check_scope(
+        req.extensions().get::<Arc<ApiKey>>().map(Arc::as_ref),
+        &API_KEY_STORE.anonymous_scopes,
+        scope,
+    )?;
+    Ok(next.run(req).await)
+}
+
+// 非http请求(如grpc)的api key校验，包括认证与权限，返回认证的api key用于限流
+pub fn verify_api_key(key: Option<&str>, scope: Scope) -> HTTPResult<Option<Arc<ApiKey>>> {
+    let store = &API_KEY_STORE;
+    if !store.is_enabled() {
+        return Ok(None);
+    }
+    let api_key = match key {
+        Some(key) => {
+            let Some(api_key) = store.keys.get(key) else {
+                return Err(HTTPError::new_with_category_status(
+                    "Api key is invalid",
+                    "unauthorized",
+                    401,
+                ));
+            };
+            api_key.record_usage();
+            Some(api_key.clone())
+        }
+        None => None,
+    };
+    check_scope(api_key.as_deref(), &store.anonymous_scopes, scope)?;
+    Ok(api_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_api_key(scopes: &[Scope]) -> ApiKey {
+        ApiKey::new(ApiKeyConfig {
+            name: "test".to_string(),
+            key: "abc".to_string(),
+            scopes: scopes.to_vec(),
+        })
+    }
+
+    fn new_request(uri: &str, header: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().uri(uri);
+        if let Some(value) = header {
+            builder = builder.header(&X_API_KEY, value);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn get_api_key_from_request() {
+        for (uri, header, expected) in [
+            ("/api/ip-locations/1.1.1.1", Some("abc"), Some("abc")),
+            (
+                "/api/ip-locations/1.1.1.1?api_key=def",
+                Some("abc"),
+                Some("abc"),
+            ),
+            ("/api/ip-locations/1.1.1.1?api_key=abc", None, Some("abc")),
+            (
+                "/api/ip-locations/1.1.1.1?lang=en&api_key=a%2Bb%2F%3D",
+                None,
+                Some("a+b/="),
+            ),
+            ("/api/ip-locations/1.1.1.1?api_key=a+b", None, Some("a b")),
+            ("/api/ip-locations/1.1.1.1?api_key=", None, None),
+            ("/api/ip-locations/1.1.1.1?key=abc", None, None),
+            ("/api/ip-locations/1.1.1.1", None, None),
+        ] {
+            assert_eq!(
+                expected.map(|value| value.to_string()),
+                get_api_key(&new_request(uri, header)),
+                "{uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_anonymous_scope() {
+        let anonymous_scopes = HashSet::from([Scope::Lookup]);
+        assert!(check_scope(None, &anonymous_scopes, Scope::Lookup).is_ok());
+        // 未带api key时无权限返回401
+        for scope in [Scope::Batch, Scope::Admin] {
+            let err = check_scope(None, &anonymous_scopes, scope).unwrap_err();
+            assert_eq!(401, err.status);
+            assert_eq!("unauthorized", err.category);
+        }
+        let err = check_scope(None, &HashSet::new(), Scope::Lookup).unwrap_err();
+        assert_eq!(401, err.status);
+    }
+
+    #[test]
+    fn check_api_key_scope() {
+        let anonymous_scopes = HashSet::from([Scope::Lookup, Scope::Batch]);
+        let api_key = new_api_key(&[Scope::Batch]);
+        assert!(check_scope(Some(&api_key), &anonymous_scopes, Scope::Batch).is_ok());
+        // 带api key时仅按其权限校验，不使用匿名权限，无权限返回403
+        for scope in [Scope::Lookup, Scope::Admin] {
+            let err = check_scope(Some(&api_key), &anonymous_scopes, scope).unwrap_err();
+            assert_eq!(403, err.status);
+            assert_eq!("forbidden", err.category);
+        }
+        let api_key = new_api_key(&[]);
+        let err = check_scope(Some(&api_key), &anonymous_scopes, Scope::Lookup).unwrap_err();
+        assert_eq!(403, err.status);
+    }
+}
@@ -0,0 +1,199 @@
+use axum::http::{HeaderName, HeaderValue, Method};
+use std::str::FromStr;
+use std::time::Duration;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::config;
+
+// cors的配置
+#[derive(Debug, Clone, Default)]
+struct CorsOptions {
+    // 允许的来源，*表示任意来源
+    origins: Vec<String>,
+    methods: Vec<String>,
+    allow_headers: Vec<String>,
+    expose_headers: Vec<String>,
+    // preflight结果的缓存时长(秒)
+    max_age: u64,
+    allow_credentials: bool,
+}
+
+impl CorsOptions {
+    fn from_env() -> Self {
+        let mut methods = config::get_env_list("CORS_ALLOW_METHODS");
+        if methods.is_empty() {
+            methods = vec!["GET".to_string(), "POST".to_string()];
+        }
+        let mut allow_headers = config::get_env_list("CORS_ALLOW_HEADERS");
+        if allow_headers.is_empty() {
+            allow_headers = vec![
+                "content-type".to_string(),
+                "x-api-key".to_string(),
+                "x-request-id".to_string(),
+            ];
+        }
+        let mut expose_headers = config::get_env_list("CORS_EXPOSE_HEADERS");
+        if expose_headers.is_empty() {
+            expose_headers = vec![
+                "x-request-id".to_string(),
+                "ratelimit-limit".to_string(),
+                "ratelimit-remaining".to_string(),
+                "ratelimit-reset".to_string(),
+            ];
+        }
+        Self {
+            origins: config::get_env_list("CORS_ALLOW_ORIGINS"),
+            methods,
+            allow_headers,
+            expose_headers,
+            max_age: config::get_env_number("CORS_MAX_AGE", 3600_u64),
+            allow_credentials: config::get_env_bool("CORS_ALLOW_CREDENTIALS", false),
+        }
+    }
+}
+
+// 根据配置生成cors，未配置CORS_ALLOW_ORIGINS则不启用
+pub fn new_cors_layer() -> Option<CorsLayer> {
+    new_cors_layer_with(CorsOptions::from_env())
+}
+
+fn new_cors_layer_with(options: CorsOptions) -> Option<CorsLayer> {
+    let origins = options.origins;
+    if origins.is_empty() {
+        return None;
+    }
+    let allow_origin = if origins.iter().any(|value| value == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|value| HeaderValue::from_str(value).ok()),
+        )
+    };
+
+    let to_headers = |values: Vec<String>| -> Vec<HeaderName> {
+        values
+            .iter()
+            .filter_map(|value| HeaderName::from_str(value).ok())
+            .collect()
+    };
+
+    let mut cors = CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(
+            options
+                .methods
+                .iter()
+                .filter_map(|value| Method::from_str(&value.to_uppercase()).ok())
+                .collect::<Vec<_>>(),
+        )
+        .allow_headers(to_headers(options.allow_headers))
+        .expose_headers(to_headers(options.expose_headers))
+        .max_age(Duration::from_secs(options.max_age));
+    // 允许任意来源时不可设置credentials
+    if options.allow_credentials && !origins.iter().any(|v| v == "*") {
+        cors = cors.allow_credentials(true);
+    }
+    Some(cors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::response::Response;
+    use axum::{routing::get, Router};
+    use tower::ServiceExt;
+
+    fn new_options(origins: &[&str]) -> CorsOptions {
+        CorsOptions {
+            origins: origins.iter().map(|value| value.to_string()).collect(),
+            methods: vec!["get".to_string(), "POST".to_string()],
+            allow_headers: vec!["content-type".to_string(), "x-api-key".to_string()],
+            expose_headers: vec!["x-request-id".to_string()],
+            max_age: 600,
+            allow_credentials: true,
+        }
+    }
+
+    async fn preflight(options: CorsOptions, origin: &str) -> Response {
+        let app = Router::new()
+            .route("/ip-locations", get(|| async { "ok" }))
+            .layer(new_cors_layer_with(options).unwrap());
+        let req = Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/ip-locations")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "x-api-key")
+            .body(Body::empty())
+            .unwrap();
+        app.oneshot(req).await.unwrap()
+    }
+
+    fn get_header(resp: &Response, name: HeaderName) -> Option<&str> {
+        resp.headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn disabled_without_origins() {
+        assert!(new_cors_layer_with(new_options(&[])).is_none());
+    }
+
+    #[tokio::test]
+    async fn preflight_allowed_origin() {
+        let resp = preflight(
+            new_options(&["https://a.example.com", "https://b.example.com"]),
+            "https://b.example.com",
+        )
+        .await;
+        assert_eq!(StatusCode::OK, resp.status());
+        assert_eq!(
+            Some("https://b.example.com"),
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+        assert_eq!(
+            Some("GET,POST"),
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_METHODS)
+        );
+        assert_eq!(
+            Some("content-type,x-api-key"),
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_HEADERS)
+        );
+        assert_eq!(
+            Some("600"),
+            get_header(&resp, header::ACCESS_CONTROL_MAX_AGE)
+        );
+        assert_eq!(
+            Some("true"),
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+        );
+    }
+
+    #[tokio::test]
+    async fn preflight_rejected_origin() {
+        let resp = preflight(
+            new_options(&["https://a.example.com"]),
+            "https://evil.example.com",
+        )
+        .await;
+        assert_eq!(None, get_header(&resp, header::ACCESS_CONTROL_ALLOW_ORIGIN));
+    }
+
+    #[tokio::test]
+    async fn ignore_credentials_with_any_origin() {
+        let resp = preflight(new_options(&["*"]), "https://evil.example.com").await;
+        assert_eq!(
+            Some("*"),
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+        assert_eq!(
+            None,
+            get_header(&resp, header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use axum::{
+    body::Body,
+    http::{header, HeaderValue, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::format;
+use crate::i18n;
+
+// 获取请求的响应格式、字段调整与语言并添加至request extensions，出错时也按此格式输出
+pub async fn response_format(mut req: Request<Body>, next: Next) -> Response {
+    let problem = format::accepts_problem(req.headers());
+    let format = match format::get_format(req.uri().query(), req.headers()) {
+        Ok(format) => format,
+        Err(err) => {
+            return format::convert_error(format::Format::Json, problem, err.into_response())
+        }
+    };
+    let shape = match format::get_shape(req.uri().query()) {
+        Ok(shape) => shape,
+        Err(err) => return format::convert_error(format, problem, err.into_response()),
+    };
+    let lang = match i18n::get_lang(req.uri().query(), req.headers()) {
+        Ok(lang) => lang,
+        Err(err) => return format::convert_error(format, problem, err.into_response()),
+    };
+    req.extensions_mut().insert(format);
+    req.extensions_mut().insert(shape);
+    req.extensions_mut().insert(lang);
+    let mut resp = next.run(req).await;
+    // 响应数据根据Accept与Accept-Language而不同
+    resp.headers_mut().append(
+        header::VARY,
+        HeaderValue::from_static("accept, accept-language"),
+    );
+    format::convert_error(format, problem, resp)
+}
@@ -0,0 +1,345 @@
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{header, HeaderValue, Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::collections::HashSet;
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::info;
+
+use crate::config;
+use crate::error::HTTPError;
+use crate::forward_auth;
+use crate::ip::{self, Location};
+
+// 地区访问控制策略
+#[derive(Debug, Clone, Default)]
+pub struct GeoPolicy {
+    // 允许的国家(ISO代码)，为空表示不限制
+    pub allow_countries: HashSet<String>,
+    // 禁止的国家(ISO代码)
+    pub deny_countries: HashSet<String>,
+    // 允许的省份，为空表示不限制
+    pub allow_provinces: HashSet<String>,
+    // 禁止的省份
+    pub deny_provinces: HashSet<String>,
+    // 无法获取位置的ip是否允许访问
+    pub allow_unknown: bool,
+    // 被禁止时重定向的地址，未设置则返回403
+    pub redirect: Option<String>,
+    // 不做访问控制的路径
+    pub skip_paths: Vec<String>,
+}
+
+fn to_set(values: Vec<String>) -> HashSet<String> {
+    values
+        .into_iter()
+        .map(|value| value.to_lowercase())
+        .collect()
+}
+
+impl GeoPolicy {
+    // 从环境变量中读取策略
+    pub fn from_env() -> Self {
+        let mut skip_paths = config::get_env_list("GEO_ACCESS_SKIP");
+        if skip_paths.is_empty() {
+            skip_paths = vec![
+                "/ping".to_string(),
+                "/healthz".to_string(),
+                "/readyz".to_string(),
+                "/metrics".to_string(),
+            ];
+        }
+        Self {
+            allow_countries: to_set(config::get_env_list("GEO_ALLOW_COUNTRIES")),
+            deny_countries: to_set(config::get_env_list("GEO_DENY_COUNTRIES")),
+            allow_provinces: to_set(config::get_env_list("GEO_ALLOW_PROVINCES")),
+            deny_provinces: to_set(config::get_env_list("GEO_DENY_PROVINCES")),
+            allow_unknown: config::get_env_bool("GEO_ALLOW_UNKNOWN", false),
+            redirect: config::get_env("GEO_BLOCK_REDIRECT"),
+            skip_paths,
+        }
+    }
+    // 是否有配置任何规则
+    pub fn is_enabled(&self) -> bool {
+        !self.allow_countries.is_empty()
+            || !self.deny_countries.is_empty()
+            || !self.allow_provinces.is_empty()
+            || !self.deny_provinces.is_empty()
+    }
+    fn is_skipped(&self, path: &str) -> bool {
+        self.skip_paths.iter().any(|value| value == path)
+    }
+    // 判断该位置是否允许访问
+    pub fn is_allowed(&self, location: &Location) -> bool {
+        if location.country.is_empty() {
+            return self.allow_unknown;
+        }
+        let country = location.country.to_lowercase();
+        let province = location.province.to_lowercase();
+        if self.deny_countries.contains(&country) || self.deny_provinces.contains(&province) {
+            return false;
+        }
+        if self.allow_countries.is_empty() && self.allow_provinces.is_empty() {
+            return true;
+        }
+        self.allow_countries.contains(&country) || self.allow_provinces.contains(&province)
+    }
+    // 获取ip对应的位置并判断是否允许访问
+    pub fn check_ip(&self, ip: &IpAddr) -> (bool, Location) {
+        let location = ip::lookup(&ip.to_string()).unwrap_or_default();
+        (self.is_allowed(&location), location)
+    }
+    // 被禁止访问时的响应
+    pub fn blocked_response(&self) -> Response {
+        if let Some(redirect) = &self.redirect {
+            if let Ok(value) = HeaderValue::from_str(redirect) {
+                return (
+                    StatusCode::FOUND,
+                    [
+                        (header::LOCATION, value),
+                        (header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+                    ],
+                )
+                    .into_response();
+            }
+        }
+        blocked_error().into_response()
+    }
+}
+
+// 被禁止访问时的出错
+pub fn blocked_error() -> HTTPError {
+    HTTPError::new_with_category_status(
+        "Access from your location is not allowed",
+        "geoBlocked",
+        403,
+    )
+}
+
+// 基于客户端ip所在地区的访问控制
+#[derive(Clone)]
+pub struct GeoAccessLayer {
+    policy: Arc<GeoPolicy>,
+}
+
+impl GeoAccessLayer {
+    pub fn new(policy: GeoPolicy) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl<S> Layer<S> for GeoAccessLayer {
+    type Service = GeoAccess<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GeoAccess {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GeoAccess<S> {
+    inner: S,
+    policy: Arc<GeoPolicy>,
+}
+
+impl<S> Service<Request<Body>> for GeoAccess<S>
+where
+    S: Service<Request<Body>, Response = Response> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let policy = self.policy.clone();
+        if policy.is_enabled() && !policy.is_skipped(req.uri().path()) {
+            // 与forward auth一致，仅信任可信代理设置的请求头
+            match req.extensions().get::<ConnectInfo<SocketAddr>>() {
+                Some(ConnectInfo(addr)) => {
+                    let ip = forward_auth::get_client_ip(req.headers(), addr.ip());
+                    let (allowed, location) = policy.check_ip(&ip);
+                    if !allowed {
+                        info!(
+                            category = "geoBlocked",
+                            ip = ip.to_string(),
+                            country = location.country,
+                            province = location.province,
+                        );
+                        let resp = policy.blocked_response();
+                        return Box::pin(async move { Ok(resp) });
+                    }
+                }
+                None => {
+                    let resp = HTTPError::new_with_category_status(
+                        "Client ip is unknown",
+                        "clientIp",
+                        500,
+                    )
+                    .into_response();
+                    return Box::pin(async move { Ok(resp) });
+                }
+            }
+        }
+        Box::pin(self.inner.call(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+    use tower::ServiceExt;
+
+    fn to_policy_set(values: &[&str]) -> HashSet<String> {
+        to_set(values.iter().map(|value| value.to_string()).collect())
+    }
+
+    fn new_location(country: &str, province: &str) -> Location {
+        Location {
+            country: country.to_string(),
+            province: province.to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn request(policy: GeoPolicy, path: &str, ip: Option<&str>) -> Response {
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .route("/metrics", get(|| async { "metrics" }))
+            .layer(GeoAccessLayer::new(policy));
+        let mut req = Request::builder().uri(path).body(Body::empty()).unwrap();
+        if let Some(ip) = ip {
+            let addr = SocketAddr::new(ip.parse().unwrap(), 5000);
+            req.extensions_mut().insert(ConnectInfo(addr));
+        }
+        app.oneshot(req).await.unwrap()
+    }
+
+    #[test]
+    fn skip_metrics_by_default() {
+        let policy = GeoPolicy::from_env();
+        for path in ["/ping", "/healthz", "/readyz", "/metrics"] {
+            assert!(policy.is_skipped(path), "{path}");
+        }
+        assert!(!policy.is_skipped("/api/ip-locations/1.1.1.1"));
+    }
+
+    #[test]
+    fn deny_before_allow() {
+        let policy = GeoPolicy {
+            allow_countries: to_policy_set(&["AU", "CN"]),
+            deny_countries: to_policy_set(&["CN"]),
+            deny_provinces: to_policy_set(&["Victoria"]),
+            ..Default::default()
+        };
+        assert!(policy.is_allowed(&new_location("AU", "Western Australia")));
+        assert!(!policy.is_allowed(&new_location("AU", "Victoria")));
+        assert!(!policy.is_allowed(&new_location("CN", "")));
+        assert!(!policy.is_allowed(&new_location("JP", "Hiroshima")));
+    }
+
+    #[test]
+    fn allow_by_province() {
+        let policy = GeoPolicy {
+            allow_provinces: to_policy_set(&["Hiroshima"]),
+            ..Default::default()
+        };
+        assert!(policy.is_allowed(&new_location("JP", "hiroshima")));
+        assert!(!policy.is_allowed(&new_location("JP", "Tottori")));
+
+        // 允许国家或允许省份满足其一即可
+        let policy = GeoPolicy {
+            allow_countries: to_policy_set(&["CN"]),
+            allow_provinces: to_policy_set(&["Hiroshima"]),
+            ..Default::default()
+        };
+        assert!(policy.is_allowed(&new_location("CN", "")));
+        assert!(policy.is_allowed(&new_location("JP", "Hiroshima")));
+        assert!(!policy.is_allowed(&new_location("AU", "Victoria")));
+    }
+
+    #[test]
+    fn allow_unknown_location() {
+        let mut policy = GeoPolicy {
+            deny_countries: to_policy_set(&["CN"]),
+            ..Default::default()
+        };
+        assert!(!policy.is_allowed(&Location::default()));
+        policy.allow_unknown = true;
+        assert!(policy.is_allowed(&Location::default()));
+        assert!(policy.check_ip(&"127.0.0.1".parse().unwrap()).0);
+        assert!(!policy.check_ip(&"1.0.1.1".parse().unwrap()).0);
+    }
+
+    #[tokio::test]
+    async fn block_by_layer() {
+        let policy = GeoPolicy {
+            deny_countries: to_policy_set(&["CN"]),
+            skip_paths: vec!["/metrics".to_string()],
+            ..Default::default()
+        };
+        let resp = request(policy.clone(), "/", Some("1.0.1.1")).await;
+        assert_eq!(StatusCode::FORBIDDEN, resp.status());
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("geoBlocked"));
+
+        let resp = request(policy.clone(), "/", Some("1.0.0.127")).await;
+        assert_eq!(StatusCode::OK, resp.status());
+
+        // 跳过的路径不做访问控制
+        let resp = request(policy.clone(), "/metrics", Some("1.0.1.1")).await;
+        assert_eq!(StatusCode::OK, resp.status());
+
+        // 无客户端地址
+        let resp = request(policy, "/", None).await;
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, resp.status());
+    }
+
+    #[tokio::test]
+    async fn redirect_blocked_request() {
+        let policy = GeoPolicy {
+            allow_countries: to_policy_set(&["JP"]),
+            redirect: Some("https://example.com/blocked".to_string()),
+            ..Default::default()
+        };
+        let resp = request(policy.clone(), "/", Some("1.0.1.1")).await;
+        assert_eq!(StatusCode::FOUND, resp.status());
+        assert_eq!(
+            "https://example.com/blocked",
+            resp.headers().get(header::LOCATION).unwrap()
+        );
+        assert_eq!(
+            "no-cache",
+            resp.headers().get(header::CACHE_CONTROL).unwrap()
+        );
+
+        let resp = request(policy, "/", Some("1.0.73.255")).await;
+        assert_eq!(StatusCode::OK, resp.status());
+    }
+
+    #[tokio::test]
+    async fn disabled_policy() {
+        // 未配置规则时不做访问控制，也不要求客户端地址
+        let resp = request(GeoPolicy::default(), "/", None).await;
+        assert_eq!(StatusCode::OK, resp.status());
+    }
+}
@@ -1,26 +1,140 @@
-use axum::{body::Body, http::Request, middleware::Next, response::Response};
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{HeaderName, HeaderValue, Request, Uri},
+    middleware::Next,
+    response::Response,
+};
 use axum_client_ip::InsecureClientIp;
 use chrono::Utc;
-use tracing::{event, Level};
+use tracing::{event, field, info_span, Instrument, Level};
 
+use crate::config;
+use crate::context::{clone_value_from_task_local, REQUEST_ID, STARTED_AT};
 use crate::error::HTTPResult;
+use crate::ip;
+use crate::metrics;
+use crate::telemetry;
+use std::sync::LazyLock;
 
-pub fn clone_value_from_task_local<T>(value: &T) -> T
-where
-    T: Clone,
-{
-    value.clone()
+mod api_key;
+mod cors;
+mod format;
+mod geo_access;
+mod rate_limit;
+
+pub use api_key::{
+    authenticate, get_api_key_usages, require_scope, verify_api_key, ApiKey, ApiKeyUsage, Scope,
+};
+pub use cors::new_cors_layer;
+pub use format::response_format;
+pub use geo_access::{blocked_error, GeoAccessLayer, GeoPolicy};
+pub use rate_limit::{
+    check_auth_failures, check_rate_limit, limit_auth_failures, rate_limit, record_auth_failure,
+};
+
+// 访问日志的target，可用于将访问日志输出至单独的文件
+pub const ACCESS_LOG_TARGET: &str = "access_log";
+
+struct AccessLogConfig {
+    // 是否记录客户端ip的位置
+    geo: bool,
+    // 采样率(0-1)，出错的请求始终记录
+    sample_rate: f64,
+    // 不记录的路径，支持前缀(/assets/*)与后缀(*.js)匹配
+    excludes: Vec<String>,
 }
 
-tokio::task_local! {
-    pub static STARTED_AT: i64;
+static ACCESS_LOG_CONFIG: LazyLock<AccessLogConfig> = LazyLock::new(|| AccessLogConfig {
+    geo: config::get_env_bool("ACCESS_LOG_GEO", false),
+    sample_rate: config::get_env_number("ACCESS_LOG_SAMPLE_RATE", 1.0_f64),
+    excludes: config::get_env_list("ACCESS_LOG_EXCLUDE"),
+});
+
+impl AccessLogConfig {
+    fn is_excluded(&self, path: &str) -> bool {
+        self.excludes.iter().any(|pattern| {
+            if let Some(suffix) = pattern.strip_prefix('*') {
+                path.ends_with(suffix)
+            } else if let Some(prefix) = pattern.strip_suffix('*') {
+                path.starts_with(prefix)
+            } else {
+                path == pattern
+            }
+        })
+    }
+    fn should_log(&self, path: &str, status: u16) -> bool {
+        if self.is_excluded(path) {
+            return false;
+        }
+        if status >= 400 || self.sample_rate >= 1.0 {
+            return true;
+        }
+        rand::random::<f64>() < self.sample_rate
+    }
+}
+
+static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+// 获取请求id，若请求头未指定则生成
+fn get_request_id(req: &Request<Body>) -> String {
+    req.headers()
+        .get(&X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty() && value.len() <= 128)
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string())
 }
 
 pub async fn entry(req: Request<Body>, next: Next) -> Response {
+    let request_id = get_request_id(&req);
+    let span = info_span!(
+        "request",
+        otel.kind = "server",
+        http.request.method = req.method().as_str(),
+        url.path = req.uri().path(),
+        request_id = request_id.as_str(),
+        http.response.status_code = field::Empty,
+    );
+    // 如果有traceparent，则作为上级trace
+    telemetry::set_parent_from_headers(&span, req.headers());
+
     // 设置请求处理开始时间
-    STARTED_AT
-        .scope(Utc::now().timestamp_millis(), async { next.run(req).await })
-        .await
+    let mut resp = STARTED_AT
+        .scope(
+            Utc::now().timestamp_millis(),
+            REQUEST_ID.scope(request_id.clone(), next.run(req)),
+        )
+        .instrument(span.clone())
+        .await;
+
+    span.record("http.response.status_code", resp.status().as_u16());
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        resp.headers_mut().insert(X_REQUEST_ID.clone(), value);
+    }
+    telemetry::inject_headers(&span, resp.headers_mut());
+    resp
+}
+
+// 访问日志中隐藏query中的api key
+fn mask_api_key(uri: &Uri) -> String {
+    // http2的uri包含scheme与host，只记录path与query
+    let Some(query) = uri.query() else {
+        return uri.path().to_string();
+    };
+    let query = query
+        .split('&')
+        .map(|item| {
+            if item.starts_with("api_key=") {
+                "api_key=***"
+            } else {
+                item
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{query}", uri.path())
 }
 
 pub async fn access_log(
@@ -29,18 +143,43 @@ pub async fn access_log(
     next: Next,
 ) -> HTTPResult<Response> {
     let start_at = STARTED_AT.with(clone_value_from_task_local);
-    let uri = req.uri().to_string();
+    let request_id = REQUEST_ID.with(clone_value_from_task_local);
+    let uri = mask_api_key(req.uri());
+    let path = req.uri().path().to_string();
     let method = req.method().to_string();
+    // 使用匹配的路由作为指标的label，避免label过多
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|value| value.as_str().to_string())
+        .unwrap_or_else(|| "fallback".to_string());
 
     let resp = next.run(req).await;
 
     let status = resp.status().as_u16();
 
     let cost = Utc::now().timestamp_millis() - start_at;
+    metrics::observe_http_request(&route, &method, status, cost);
+
+    let access_log_config = &ACCESS_LOG_CONFIG;
+    if !access_log_config.should_log(&path, status) {
+        return Ok(resp);
+    }
+    let ip = ip.to_string();
+    let location = if access_log_config.geo {
+        ip::lookup(&ip).ok()
+    } else {
+        None
+    };
     event!(
+        target: ACCESS_LOG_TARGET,
         Level::INFO,
         category = "accessLog",
-        ip = ip.to_string(),
+        request_id,
+        ip,
+        country = location.as_ref().map(|value| value.country.as_str()),
+        province = location.as_ref().map(|value| value.province.as_str()),
+        city = location.as_ref().map(|value| value.city.as_str()),
         method,
         uri,
         status,
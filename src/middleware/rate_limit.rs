@@ -0,0 +1,371 @@
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderName, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::error::HTTPError;
+use crate::forward_auth;
+
+use super::ApiKey;
+
+static RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+// 超过此数量时清除已回满的令牌桶
+const MAX_BUCKETS: usize = 10_000;
+// 清除的最小间隔，避免每次请求都遍历所有令牌桶
+const SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy)]
+struct Limit {
+    // 令牌桶容量(周期内的请求数)
+    capacity: f64,
+    // 每秒补充的令牌数
+    refill: f64,
+}
+
+impl Limit {
+    fn new(capacity: u64, period: u64) -> Option<Self> {
+        if capacity == 0 {
+            return None;
+        }
+        Some(Self {
+            capacity: capacity as f64,
+            refill: capacity as f64 / period.max(1) as f64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    limit: Limit,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.refill).min(self.limit.capacity);
+        self.updated_at = now;
+    }
+    fn is_full(&self) -> bool {
+        self.tokens >= self.limit.capacity
+    }
+}
+
+struct Buckets {
+    items: HashMap<String, Bucket>,
+    swept_at: Instant,
+}
+
+struct RateLimiter {
+    ip_limit: Option<Limit>,
+    api_key_limit: Option<Limit>,
+    buckets: Mutex<Buckets>,
+}
+
+// 限制结果
+struct RateLimitState {
+    allowed: bool,
+    limit: u64,
+    remaining: u64,
+    // 多少秒后可再次请求(被限制时)或令牌桶回满
+    reset: u64,
+}
+
+static RATE_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| {
+    let period = config::get_env_number("RATE_LIMIT_PERIOD", 60_u64);
+    RateLimiter::new(
+        Limit::new(config::get_env_number("RATE_LIMIT", 0_u64), period),
+        Limit::new(config::get_env_number("RATE_LIMIT_API_KEY", 0_u64), period),
+    )
+});
+
+impl RateLimiter {
+    fn new(ip_limit: Option<Limit>, api_key_limit: Option<Limit>) -> Self {
+        Self {
+            ip_limit,
+            api_key_limit,
+            buckets: Mutex::new(Buckets {
+                items: HashMap::new(),
+                swept_at: Instant::now(),
+            }),
+        }
+    }
+    fn acquire(&self, key: String, limit: &Limit, now: Instant) -> RateLimitState {
+        self.take(key, limit, now, 1.0)
+    }
+    // 获取count个令牌，为0时仅检查是否还有令牌
+    fn take(&self, key: String, limit: &Limit, now: Instant, count: f64) -> RateLimitState {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        // 按间隔清除，平摊遍历的开销
+        if buckets.items.len() > MAX_BUCKETS
+            && now.duration_since(buckets.swept_at) >= SWEEP_INTERVAL
+        {
+            buckets.items.retain(|_, bucket| {
+                bucket.refill(now);
+                !bucket.is_full()
+            });
+            buckets.swept_at = now;
+        }
+        let bucket = buckets.items.entry(key).or_insert(Bucket {
+            limit: *limit,
+            tokens: limit.capacity,
+            updated_at: now,
+        });
+        bucket.refill(now);
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= count;
+        }
+        let reset = if allowed {
+            (limit.capacity - bucket.tokens) / limit.refill
+        } else {
+            (1.0 - bucket.tokens) / limit.refill
+        };
+        RateLimitState {
+            allowed,
+            limit: limit.capacity as u64,
+            remaining: bucket.tokens.floor() as u64,
+            reset: reset.ceil() as u64,
+        }
+    }
+}
+
+fn set_rate_limit_headers(resp: &mut Response, state: &RateLimitState) {
+    let headers = resp.headers_mut();
+    headers.insert(RATE_LIMIT_LIMIT.clone(), HeaderValue::from(state.limit));
+    headers.insert(
+        RATE_LIMIT_REMAINING.clone(),
+        HeaderValue::from(state.remaining),
+    );
+    headers.insert(RATE_LIMIT_RESET.clone(), HeaderValue::from(state.reset));
+}
+
+// 认证失败(401)的限制，按客户端ip计算，避免暴力猜测api key
+static AUTH_FAILURE_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| {
+    let period = config::get_env_number("RATE_LIMIT_PERIOD", 60_u64);
+    RateLimiter::new(
+        Limit::new(config::get_env_number("AUTH_FAILURE_LIMIT", 10_u64), period),
+        None,
+    )
+});
+
+// 该ip的认证失败次数是否已超出限制
+pub fn check_auth_failures(ip: IpAddr) -> Result<(), HTTPError> {
+    let limiter = &AUTH_FAILURE_LIMITER;
+    let Some(limit) = limiter.ip_limit else {
+        return Ok(());
+    };
+    let state = limiter.take(format!("ip:{ip}"), &limit, Instant::now(), 0.0);
+    if state.allowed {
+        return Ok(());
+    }
+    Err(HTTPError::new_with_category_status(
+        "Too many authentication failures",
+        "rateLimited",
+        429,
+    ))
+}
+
+// 记录认证失败
+pub fn record_auth_failure(ip: IpAddr) {
+    let limiter = &AUTH_FAILURE_LIMITER;
+    if let Some(limit) = limiter.ip_limit {
+        limiter.acquire(format!("ip:{ip}"), &limit, Instant::now());
+    }
+}
+
+// 在认证之前执行，认证失败次数超出限制的ip直接返回429
+pub async fn limit_auth_failures(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let ip = forward_auth::get_client_ip(req.headers(), addr.ip());
+    if let Err(err) = check_auth_failures(ip) {
+        return err.into_response();
+    }
+    let resp = next.run(req).await;
+    if resp.status() == StatusCode::UNAUTHORIZED {
+        record_auth_failure(ip);
+    }
+    resp
+}
+
+// 超出限制时返回429，并设置Retry-After
+fn too_many_requests(state: &RateLimitState) -> Response {
+    let mut resp = HTTPError::new_with_category_status("Too many requests", "rateLimited", 429)
+        .into_response();
+    set_rate_limit_headers(&mut resp, state);
+    resp.headers_mut().insert(
+        axum::http::header::RETRY_AFTER,
+        HeaderValue::from(state.reset),
+    );
+    resp
+}
+
+// 已认证的api key则按api key限制，否则按客户端ip限制
+fn get_limit_key(
+    limiter: &RateLimiter,
+    ip: IpAddr,
+    api_key: Option<&ApiKey>,
+) -> (String, Option<Limit>) {
+    match api_key {
+        Some(api_key) if limiter.api_key_limit.is_some() => {
+            (format!("key:{}", api_key.name), limiter.api_key_limit)
+        }
+        _ => (format!("ip:{ip}"), limiter.ip_limit),
+    }
+}
+
+// 非http请求(如grpc)的限流，与http共用令牌桶
+pub fn check_rate_limit(ip: IpAddr, api_key: Option<&ApiKey>) -> Result<(), HTTPError> {
+    let limiter = &RATE_LIMITER;
+    let (key, limit) = get_limit_key(limiter, ip, api_key);
+    let Some(limit) = limit else {
+        return Ok(());
+    };
+    let state = limiter.acquire(key, &limit, Instant::now());
+    if state.allowed {
+        return Ok(());
+    }
+    Err(HTTPError::new_with_category_status(
+        &format!("Too many requests, retry after {}s", state.reset),
+        "rateLimited",
+        429,
+    ))
+}
+
+// 令牌桶限流，已认证的api key则按api key限制，否则按客户端ip限制
+// 客户端ip为连接的ip，仅当来自可信代理时才使用X-Forwarded-For等请求头
+pub async fn rate_limit(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let limiter = &RATE_LIMITER;
+    let ip = forward_auth::get_client_ip(req.headers(), addr.ip());
+    let api_key = req.extensions().get::<Arc<ApiKey>>().map(Arc::as_ref);
+    let (key, limit) = get_limit_key(limiter, ip, api_key);
+    let Some(limit) = limit else {
+        return next.run(req).await;
+    };
+    let state = limiter.acquire(key, &limit, Instant::now());
+    if !state.allowed {
+        return too_many_requests(&state);
+    }
+    let mut resp = next.run(req).await;
+    set_rate_limit_headers(&mut resp, &state);
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::header;
+
+    fn new_limiter() -> (RateLimiter, Limit) {
+        // 每分钟2次，即每30秒补充1个令牌
+        let limit = Limit::new(2, 60).unwrap();
+        (RateLimiter::new(Some(limit), None), limit)
+    }
+
+    #[test]
+    fn limit_requests() {
+        let (limiter, limit) = new_limiter();
+        let now = Instant::now();
+        let state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, now);
+        assert!(state.allowed);
+        assert_eq!(2, state.limit);
+        assert_eq!(1, state.remaining);
+        let state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, now);
+        assert!(state.allowed);
+        assert_eq!(0, state.remaining);
+        assert_eq!(60, state.reset);
+        let state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, now);
+        assert!(!state.allowed);
+        assert_eq!(30, state.reset);
+        // 不同的key互不影响
+        let state = limiter.acquire("ip:2.2.2.2".to_string(), &limit, now);
+        assert!(state.allowed);
+    }
+
+    #[test]
+    fn refill_tokens() {
+        let (limiter, limit) = new_limiter();
+        let now = Instant::now();
+        for _ in 0..2 {
+            assert!(
+                limiter
+                    .acquire("ip:1.1.1.1".to_string(), &limit, now)
+                    .allowed
+            );
+        }
+        let later = now + Duration::from_secs(29);
+        assert!(
+            !limiter
+                .acquire("ip:1.1.1.1".to_string(), &limit, later)
+                .allowed
+        );
+        let later = now + Duration::from_secs(30);
+        let state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, later);
+        assert!(state.allowed);
+        assert_eq!(0, state.remaining);
+    }
+
+    #[test]
+    fn respond_too_many_requests() {
+        let (limiter, limit) = new_limiter();
+        let now = Instant::now();
+        let mut state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, now);
+        while state.allowed {
+            state = limiter.acquire("ip:1.1.1.1".to_string(), &limit, now);
+        }
+        let resp = too_many_requests(&state);
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, resp.status());
+        let headers = resp.headers();
+        assert_eq!("30", headers.get(header::RETRY_AFTER).unwrap());
+        assert_eq!("2", headers.get(&RATE_LIMIT_LIMIT).unwrap());
+        assert_eq!("0", headers.get(&RATE_LIMIT_REMAINING).unwrap());
+    }
+
+    #[test]
+    fn sweep_full_buckets() {
+        let (limiter, limit) = new_limiter();
+        let now = Instant::now();
+        for i in 0..=MAX_BUCKETS {
+            limiter.acquire(format!("ip:{i}"), &limit, now);
+        }
+        // 间隔内不清除
+        limiter.acquire("ip:a".to_string(), &limit, now);
+        assert_eq!(MAX_BUCKETS + 2, limiter.buckets.lock().unwrap().items.len());
+        // 令牌已回满的桶被清除
+        let later = now + SWEEP_INTERVAL + Duration::from_secs(60);
+        limiter.acquire("ip:b".to_string(), &limit, later);
+        assert_eq!(1, limiter.buckets.lock().unwrap().items.len());
+    }
+
+    #[test]
+    fn limit_auth_failures() {
+        let ip: IpAddr = "203.0.113.10".parse().unwrap();
+        for _ in 0..10 {
+            assert!(check_auth_failures(ip).is_ok());
+            record_auth_failure(ip);
+        }
+        let err = check_auth_failures(ip).unwrap_err();
+        assert_eq!(429, err.status);
+        // 其它ip不受影响
+        assert!(check_auth_failures("203.0.113.11".parse().unwrap()).is_ok());
+    }
+}
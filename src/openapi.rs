@@ -0,0 +1,138 @@
+use axum::http::header;
+use axum::response::{Html, IntoResponse};
+use axum::Json;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::error::{ErrorCode, HTTPError, Problem};
+use crate::health::{Liveness, Readiness};
+use crate::ip::{Confidence, Location};
+use crate::middleware::{ApiKeyUsage, Scope};
+
+// api key可通过header或query传递
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+        components.add_security_scheme(
+            "apiKey",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+        );
+        components.add_security_scheme(
+            "apiKeyQuery",
+            SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::new("api_key"))),
+        );
+    }
+}
+
+// 由handler与类型定义生成的openapi文档
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Location",
+        description = "Query the location of ip, the country, province and city"
+    ),
+    paths(
+        crate::ping,
+        crate::healthz,
+        crate::readyz,
+        crate::get_metrics,
+        crate::get_location,
+        crate::get_host_locations,
+        crate::get_locations,
+        crate::get_api_key_usages,
+        crate::get_error_codes,
+        crate::forward_auth::forward_auth
+    ),
+    components(schemas(
+        Location,
+        Confidence,
+        HTTPError,
+        Problem,
+        ErrorCode,
+        Liveness,
+        Readiness,
+        ApiKeyUsage,
+        Scope
+    )),
+    modifiers(&ApiKeySecurity),
+    tags(
+        (name = "location", description = "Ip location lookup"),
+        (name = "system", description = "Service status and metadata"),
+        (name = "admin", description = "Administration, requires the admin scope"),
+        (name = "gateway", description = "Access control for the gateways")
+    )
+)]
+struct ApiDoc;
+
+pub async fn get_openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+// 文档页面，使用redoc渲染openapi.json
+// redoc(v2.0.0, MIT)内嵌于程序中，不依赖cdn
+static DOCS_HTML: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>Location API</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <redoc spec-url="./openapi.json"></redoc>
+    <script src="./docs/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
+
+pub async fn get_docs() -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Html(DOCS_HTML),
+    )
+}
+
+static REDOC_SCRIPT: &str = include_str!("../assets/redoc.standalone.js");
+
+pub async fn get_redoc_script() -> impl IntoResponse {
+    (
+        [
+            (header::CONTENT_TYPE, "text/javascript; charset=utf-8"),
+            (header::CACHE_CONTROL, "public, max-age=86400"),
+        ],
+        REDOC_SCRIPT,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_all_routes() {
+        let openapi = ApiDoc::openapi();
+        for path in [
+            "/ping",
+            "/healthz",
+            "/readyz",
+            "/metrics",
+            "/forward-auth",
+            "/api/ip-locations",
+            "/api/ip-locations/{ip}",
+            "/api/host-locations/{host}",
+            "/api/admin/api-keys",
+            "/api/errors",
+        ] {
+            assert!(openapi.paths.paths.contains_key(path), "{path}");
+        }
+    }
+
+    #[test]
+    fn docs_without_cdn() {
+        assert!(!DOCS_HTML.contains("https://"));
+        assert!(REDOC_SCRIPT.len() > 1024);
+    }
+}
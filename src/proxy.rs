@@ -0,0 +1,219 @@
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Request, Uri};
+use axum::response::{IntoResponse, Response};
+use hyper_util::client::legacy::{connect::HttpConnector, Client};
+use hyper_util::rt::TokioExecutor;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use tracing::error;
+
+use crate::config;
+use crate::error::{HTTPError, HTTPResult};
+use crate::forward_auth;
+use crate::ip;
+use crate::tls;
+
+static X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+static X_FORWARDED_HOST: HeaderName = HeaderName::from_static("x-forwarded-host");
+static X_FORWARDED_PROTO: HeaderName = HeaderName::from_static("x-forwarded-proto");
+
+// 逐跳的header，不转发
+static HOP_BY_HOP_HEADERS: [HeaderName; 8] = [
+    header::CONNECTION,
+    HeaderName::from_static("keep-alive"),
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+];
+
+// 反向代理的上游地址，如: http://127.0.0.1:3000
+static UPSTREAM: LazyLock<Option<Uri>> = LazyLock::new(|| {
+    let value = config::get_env("PROXY_UPSTREAM")?;
+    match value.parse::<Uri>() {
+        Ok(uri) if uri.scheme_str() == Some("http") && uri.authority().is_some() => Some(uri),
+        _ => {
+            error!("proxy upstream is invalid, only http is supported: {value}");
+            None
+        }
+    }
+});
+
+static CLIENT: LazyLock<Client<HttpConnector, Body>> =
+    LazyLock::new(|| Client::builder(TokioExecutor::new()).build_http());
+
+// 是否启用反向代理
+pub fn is_enabled() -> bool {
+    UPSTREAM.is_some()
+}
+
+// 除了固定的逐跳header，Connection中列出的header也需要删除(RFC 9110 7.6.1)
+fn remove_hop_by_hop_headers(headers: &mut HeaderMap) {
+    let names: Vec<HeaderName> = headers
+        .get_all(header::CONNECTION)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|name| HeaderName::from_bytes(name.trim().as_bytes()).ok())
+        .collect();
+    for name in names.iter().chain(HOP_BY_HOP_HEADERS.iter()) {
+        headers.remove(name);
+    }
+}
+
+// 生成上游的地址，上游地址的路径作为前缀
+fn get_upstream_uri(upstream: &Uri, uri: &Uri) -> HTTPResult<Uri> {
+    let prefix = upstream.path().trim_end_matches('/');
+    let path_and_query = uri
+        .path_and_query()
+        .map(|value| value.as_str())
+        .unwrap_or("/");
+    Uri::builder()
+        .scheme("http")
+        .authority(
+            upstream
+                .authority()
+                .map(|value| value.as_str())
+                .unwrap_or_default(),
+        )
+        .path_and_query(format!("{prefix}{path_and_query}"))
+        .build()
+        .map_err(|err| HTTPError::new_with_category_status(&err.to_string(), "proxy", 502))
+}
+
+// 将请求转发至上游，并添加客户端ip对应的X-Geo-*请求头
+pub async fn proxy(
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    req: Request<Body>,
+) -> HTTPResult<Response> {
+    let Some(upstream) = UPSTREAM.as_ref() else {
+        return Err(HTTPError::new_with_category_status(
+            "Proxy upstream is not configured",
+            "proxy",
+            502,
+        ));
+    };
+    forward(upstream, addr, req).await
+}
+
+async fn forward(upstream: &Uri, addr: SocketAddr, mut req: Request<Body>) -> HTTPResult<Response> {
+    let client_ip = forward_auth::get_client_ip(req.headers(), addr.ip());
+    let location = ip::lookup(&client_ip.to_string()).unwrap_or_default();
+
+    let host = req.headers().get(header::HOST).cloned();
+    *req.uri_mut() = get_upstream_uri(upstream, req.uri())?;
+    let headers = req.headers_mut();
+    remove_hop_by_hop_headers(headers);
+
+    // 客户端传入的geo header需要覆盖，避免伪造
+    for (name, value) in forward_auth::new_geo_headers(&location) {
+        if let Some(name) = name {
+            headers.insert(name, value);
+        }
+    }
+
+    let forwarded_for = match headers
+        .get(&X_FORWARDED_FOR)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => format!("{value}, {}", addr.ip()),
+        None => addr.ip().to_string(),
+    };
+    if let Ok(value) = HeaderValue::from_str(&forwarded_for) {
+        headers.insert(X_FORWARDED_FOR.clone(), value);
+    }
+    if let Some(host) = host {
+        headers.insert(X_FORWARDED_HOST.clone(), host);
+    }
+    // 使用当前连接的scheme，客户端传入的值不可信
+    let proto = if tls::is_enabled() { "https" } else { "http" };
+    headers.insert(X_FORWARDED_PROTO.clone(), HeaderValue::from_static(proto));
+    // 使用上游的host
+    if let Some(authority) = upstream.authority() {
+        if let Ok(value) = HeaderValue::from_str(authority.as_str()) {
+            headers.insert(header::HOST, value);
+        }
+    }
+
+    let resp = CLIENT.request(req).await.map_err(|err| {
+        error!(category = "proxy", "request upstream fail: {err}");
+        HTTPError::new_with_category_status("Request upstream fail", "proxy", 502)
+    })?;
+    let (mut parts, body) = resp.into_parts();
+    remove_hop_by_hop_headers(&mut parts.headers);
+    Ok(Response::from_parts(parts, Body::new(body)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Json;
+    use axum::Router;
+    use serde_json::{Map, Value};
+
+    // 本地的上游服务，返回收到的path与header
+    async fn start_upstream() -> Uri {
+        let app = Router::new().fallback(|uri: Uri, headers: HeaderMap| async move {
+            let mut result = Map::new();
+            result.insert("path".to_string(), Value::from(uri.to_string()));
+            for (name, value) in headers.iter() {
+                result.insert(
+                    name.to_string(),
+                    Value::from(value.to_str().unwrap_or_default()),
+                );
+            }
+            (
+                [("connection", "x-upstream-hop"), ("x-upstream-hop", "1")],
+                Json(result),
+            )
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{addr}/prefix").parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn forward_to_upstream() {
+        let upstream = start_upstream().await;
+        let req = Request::builder()
+            .uri("/users?page=1")
+            .header(header::HOST, "example.com")
+            .header(header::CONNECTION, "x-hop")
+            .header("x-hop", "1")
+            .header("x-forwarded-proto", "https")
+            .header("x-forwarded-for", "9.9.9.9")
+            .header("x-geo-country", "XX")
+            .body(Body::empty())
+            .unwrap();
+        let addr: SocketAddr = "1.0.1.1:5000".parse().unwrap();
+        let resp = forward(&upstream, addr, req).await.unwrap();
+        assert!(!resp.headers().contains_key("x-upstream-hop"));
+        assert!(!resp.headers().contains_key(header::CONNECTION));
+
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: Map<String, Value> = serde_json::from_slice(&body).unwrap();
+        assert_eq!("/prefix/users?page=1", result["path"]);
+        assert!(!result.contains_key("x-hop"));
+        assert_eq!("http", result["x-forwarded-proto"]);
+        assert_eq!("9.9.9.9, 1.0.1.1", result["x-forwarded-for"]);
+        assert_eq!("example.com", result["x-forwarded-host"]);
+        assert_eq!("CN", result["x-geo-country"]);
+        assert_eq!("1.0.1.1", result["x-geo-ip"]);
+    }
+
+    #[tokio::test]
+    async fn upstream_unavailable() {
+        let upstream: Uri = "http://127.0.0.1:1".parse().unwrap();
+        let req = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let addr: SocketAddr = "127.0.0.1:5000".parse().unwrap();
+        let err = forward(&upstream, addr, req).await.unwrap_err();
+        assert_eq!(502, err.status);
+        assert_eq!("proxy", err.category);
+    }
+}
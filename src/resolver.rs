@@ -0,0 +1,302 @@
+use hickory_resolver::config::{
+    LookupIpStrategy, NameServerConfig, NameServerConfigGroup, ResolverConfig, ResolverOpts,
+};
+use hickory_resolver::name_server::TokioConnectionProvider;
+use hickory_resolver::proto::xfer::Protocol;
+use hickory_resolver::TokioResolver;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use crate::config;
+use crate::error::{HTTPError, HTTPResult};
+use crate::ip::{self, Location};
+
+// 是否支持查询域名的位置，默认不支持
+pub fn is_enabled() -> bool {
+    config::get_env_bool("RESOLVE_HOSTNAMES", false)
+}
+
+// 解析的超时，包括所有dns服务器的重试
+fn timeout() -> Duration {
+    Duration::from_millis(config::get_env_number("RESOLVE_TIMEOUT", 2000_u64))
+}
+
+// 最多返回的ip数量
+fn max_addresses() -> usize {
+    config::get_env_number("RESOLVE_MAX_ADDRESSES", 8_usize)
+}
+
+// dns服务器，格式为: 1.1.1.1,127.0.0.1:5353，未设置则使用系统的配置
+fn new_name_servers() -> Option<NameServerConfigGroup> {
+    let servers: Vec<NameServerConfig> = config::get_env_list("RESOLVER_NAMESERVERS")
+        .iter()
+        .filter_map(|value| {
+            value
+                .parse::<SocketAddr>()
+                .ok()
+                .or_else(|| value.parse::<IpAddr>().ok().map(|ip| (ip, 53).into()))
+        })
+        .flat_map(|addr| {
+            [
+                NameServerConfig::new(addr, Protocol::Udp),
+                NameServerConfig::new(addr, Protocol::Tcp),
+            ]
+        })
+        .collect();
+    if servers.is_empty() {
+        return None;
+    }
+    Some(servers.into())
+}
+
+fn new_resolver() -> Result<TokioResolver, String> {
+    build_resolver(new_name_servers(), timeout())
+}
+
+fn build_resolver(
+    name_servers: Option<NameServerConfigGroup>,
+    timeout: Duration,
+) -> Result<TokioResolver, String> {
+    let mut builder = match name_servers {
+        Some(name_servers) => TokioResolver::builder_with_config(
+            ResolverConfig::from_parts(None, vec![], name_servers),
+            TokioConnectionProvider::default(),
+        ),
+        None => TokioResolver::builder_tokio().map_err(|err| err.to_string())?,
+    };
+    let opts: &mut ResolverOpts = builder.options_mut();
+    opts.timeout = timeout;
+    opts.attempts = 1;
+    opts.ip_strategy = LookupIpStrategy::Ipv4AndIpv6;
+    Ok(builder.build())
+}
+
+static RESOLVER: LazyLock<Result<TokioResolver, String>> = LazyLock::new(new_resolver);
+
+// 是否为域名，如example.com
+pub fn is_hostname(value: &str) -> bool {
+    if value.len() > 253 || value.parse::<IpAddr>().is_ok() {
+        return false;
+    }
+    let value = value.strip_suffix('.').unwrap_or(value);
+    let labels: Vec<&str> = value.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        // 顶级域名不能全为数字
+        && labels
+            .last()
+            .is_some_and(|label| label.chars().any(|c| c.is_ascii_alphabetic()))
+}
+
+// 解析域名的A与AAAA记录，去重后最多返回RESOLVE_MAX_ADDRESSES个
+pub async fn resolve(host: &str) -> HTTPResult<Vec<IpAddr>> {
+    let resolver = RESOLVER
+        .as_ref()
+        .map_err(|err| HTTPError::new_with_category_status(err, "resolver", 500))?;
+    resolve_with(resolver, host, timeout(), max_addresses()).await
+}
+
+async fn resolve_with(
+    resolver: &TokioResolver,
+    host: &str,
+    timeout: Duration,
+    max_addresses: usize,
+) -> HTTPResult<Vec<IpAddr>> {
+    let result = tokio::time::timeout(timeout, resolver.lookup_ip(host))
+        .await
+        .map_err(|_| {
+            HTTPError::new_with_category_status(
+                &format!("Resolve {host} timeout"),
+                "resolveTimeout",
+                504,
+            )
+        })?
+        .map_err(|err| {
+            let message = if err.is_no_records_found() {
+                format!("Hostname {host} has no address")
+            } else {
+                format!("Resolve {host} fail: {err}")
+            };
+            HTTPError::new_with_category(&message, "resolve")
+        })?;
+    let mut ips: Vec<IpAddr> = vec![];
+    for ip in result.iter() {
+        if !ips.contains(&ip) {
+            ips.push(ip);
+        }
+    }
+    ips.truncate(max_addresses);
+    Ok(ips)
+}
+
+// 查询域名所有ip的位置，strict模式下忽略无位置数据的ip，均无数据则返回404
+pub async fn lookup_host(host: &str, strict: bool) -> HTTPResult<Vec<Location>> {
+    if !is_enabled() {
+        return Err(HTTPError::new_with_category_status(
+            "Hostname lookup is not enabled",
+            "notFound",
+            404,
+        ));
+    }
+    if !is_hostname(host) {
+        return Err(HTTPError::new_with_category(
+            &format!("{host} is not a valid hostname"),
+            "resolve",
+        ));
+    }
+    let ips = resolve(host).await?;
+    get_host_locations(host, &ips, strict)
+}
+
+fn get_host_locations(host: &str, ips: &[IpAddr], strict: bool) -> HTTPResult<Vec<Location>> {
+    let mut locations = Vec::with_capacity(ips.len());
+    for ip in ips {
+        let location = ip::get_location(&ip.to_string())?;
+        if strict && !location.found {
+            continue;
+        }
+        locations.push(location);
+    }
+    if locations.is_empty() {
+        return Err(HTTPError::new_with_category_status(
+            &format!("No location data for {host}"),
+            "locationNotFound",
+            404,
+        ));
+    }
+    Ok(locations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hickory_resolver::proto::op::{Message, MessageType, ResponseCode};
+    use hickory_resolver::proto::rr::rdata::{A, AAAA};
+    use hickory_resolver::proto::rr::{RData, Record, RecordType};
+    use hickory_resolver::proto::serialize::binary::{BinDecodable, BinEncodable};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use tokio::net::UdpSocket;
+
+    // 模拟的dns服务器
+    // example.test有重复的A记录与AAAA记录，missing.test不存在，slow.test不响应
+    async fn start_stub_server() -> SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0; 512];
+            loop {
+                let Ok((len, peer)) = socket.recv_from(&mut buf).await else {
+                    return;
+                };
+                let Ok(req) = Message::from_bytes(&buf[..len]) else {
+                    continue;
+                };
+                let Some(query) = req.queries().first().cloned() else {
+                    continue;
+                };
+                let name = query.name().to_ascii();
+                if name == "slow.test." {
+                    continue;
+                }
+                let mut resp = Message::new();
+                resp.set_id(req.id())
+                    .set_message_type(MessageType::Response)
+                    .set_recursion_desired(true)
+                    .set_recursion_available(true)
+                    .add_query(query.clone());
+                if name == "example.test." {
+                    let rdata = match query.query_type() {
+                        RecordType::A => vec![
+                            RData::A(A(Ipv4Addr::new(1, 0, 1, 1))),
+                            RData::A(A(Ipv4Addr::new(1, 0, 1, 1))),
+                            RData::A(A(Ipv4Addr::new(192, 0, 2, 1))),
+                        ],
+                        RecordType::AAAA => vec![RData::AAAA(AAAA(Ipv6Addr::new(
+                            0x2001, 0x4860, 0, 0, 0, 0, 0, 0x8888,
+                        )))],
+                        _ => vec![],
+                    };
+                    for rdata in rdata {
+                        resp.add_answer(Record::from_rdata(query.name().clone(), 60, rdata));
+                    }
+                } else {
+                    resp.set_response_code(ResponseCode::NXDomain);
+                }
+                let _ = socket.send_to(&resp.to_bytes().unwrap(), peer).await;
+            }
+        });
+        addr
+    }
+
+    async fn new_stub_resolver() -> TokioResolver {
+        let addr = start_stub_server().await;
+        let name_servers = vec![NameServerConfig::new(addr, Protocol::Udp)];
+        build_resolver(Some(name_servers.into()), Duration::from_millis(500)).unwrap()
+    }
+
+    #[test]
+    fn hostnames() {
+        assert!(is_hostname("example.com"));
+        assert!(is_hostname("example.com."));
+        assert!(!is_hostname("localhost"));
+        assert!(!is_hostname("1.1.1.1"));
+        assert!(!is_hostname("1.2.3.4.5"));
+        assert!(!is_hostname("-a.com"));
+    }
+
+    #[tokio::test]
+    async fn resolve_addresses() {
+        let resolver = new_stub_resolver().await;
+        let timeout = Duration::from_secs(1);
+        let ips = resolve_with(&resolver, "example.test", timeout, 8)
+            .await
+            .unwrap();
+        assert_eq!(3, ips.len());
+        assert!(ips.contains(&IpAddr::from([1, 0, 1, 1])));
+        assert!(ips.contains(&IpAddr::from([192, 0, 2, 1])));
+        assert!(ips.contains(&"2001:4860::8888".parse().unwrap()));
+
+        let ips = resolve_with(&resolver, "example.test", timeout, 1)
+            .await
+            .unwrap();
+        assert_eq!(1, ips.len());
+
+        let err = resolve_with(&resolver, "missing.test", timeout, 8)
+            .await
+            .unwrap_err();
+        assert_eq!("resolve", err.category);
+        assert_eq!(400, err.status);
+
+        let err = resolve_with(&resolver, "slow.test", Duration::from_millis(200), 8)
+            .await
+            .unwrap_err();
+        assert_eq!("resolveTimeout", err.category);
+        assert_eq!(504, err.status);
+    }
+
+    #[test]
+    fn host_locations() {
+        let found = IpAddr::from([1, 0, 1, 1]);
+        let not_found = IpAddr::from([192, 0, 2, 1]);
+        let locations = get_host_locations("example.test", &[found, not_found], false).unwrap();
+        assert_eq!(2, locations.len());
+
+        // strict模式忽略无位置数据的ip
+        let locations = get_host_locations("example.test", &[found, not_found], true).unwrap();
+        assert_eq!(1, locations.len());
+        assert_eq!("1.0.1.1", locations[0].ip);
+
+        let err = get_host_locations("example.test", &[not_found], true).unwrap_err();
+        assert_eq!(404, err.status);
+        assert_eq!("locationNotFound", err.category);
+    }
+}
@@ -0,0 +1,158 @@
+use axum::http::HeaderMap;
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_http::{HeaderExtractor, HeaderInjector};
+use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider, Resource};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::config;
+
+// 是否启用otlp，设置了OTEL_EXPORTER_OTLP_ENDPOINT则启用
+pub fn is_enabled() -> bool {
+    config::get_env("OTEL_EXPORTER_OTLP_ENDPOINT").is_some()
+}
+
+// 使用W3C trace context(traceparent)传递trace
+pub fn init_propagator() {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+}
+
+// 初始化otlp的tracer provider
+// endpoint等配置由OTEL_EXPORTER_OTLP_*环境变量指定
+pub fn new_tracer_provider() -> Result<SdkTracerProvider, String> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+        .map_err(|err| err.to_string())?;
+    let provider = new_provider_with_exporter(exporter);
+    global::set_tracer_provider(provider.clone());
+    Ok(provider)
+}
+
+fn new_provider_with_exporter(exporter: opentelemetry_otlp::SpanExporter) -> SdkTracerProvider {
+    let service_name =
+        config::get_env("OTEL_SERVICE_NAME").unwrap_or_else(|| "location".to_string());
+    SdkTracerProvider::builder()
+        .with_resource(Resource::builder().with_service_name(service_name).build())
+        .with_batch_exporter(exporter)
+        .build()
+}
+
+pub fn new_tracer(provider: &SdkTracerProvider) -> opentelemetry_sdk::trace::Tracer {
+    provider.tracer("location")
+}
+
+// 从请求头(traceparent)中获取上级trace并设置
+pub fn set_parent_from_headers(span: &Span, headers: &HeaderMap) {
+    let cx =
+        global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)));
+    let _ = span.set_parent(cx);
+}
+
+// 将当前trace写入响应头(traceparent)
+pub fn inject_headers(span: &Span, headers: &mut HeaderMap) {
+    let cx = span.context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&cx, &mut HeaderInjector(headers))
+    });
+    // tracestate为空时无需返回
+    if headers
+        .get("tracestate")
+        .is_some_and(|value| value.is_empty())
+    {
+        headers.remove("tracestate");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use opentelemetry::trace::{Span as _, Tracer};
+    use opentelemetry_otlp::WithExportConfig;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    // 模拟的otlp collector，返回收到的请求行与body长度
+    fn start_collector() -> (String, mpsc::Receiver<(String, usize)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                reader
+                    .get_mut()
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                    .unwrap();
+                tx.send((request_line.trim().to_string(), body.len()))
+                    .unwrap();
+            }
+        });
+        (format!("http://{addr}/v1/traces"), rx)
+    }
+
+    #[test]
+    fn export_spans_to_collector() {
+        let (endpoint, rx) = start_collector();
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()
+            .unwrap();
+        let provider = new_provider_with_exporter(exporter);
+        let mut span = new_tracer(&provider).start("lookup");
+        span.end();
+        provider.force_flush().unwrap();
+
+        let (request_line, size) = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!("POST /v1/traces HTTP/1.1", request_line);
+        assert!(size > 0);
+        provider.shutdown().unwrap();
+    }
+
+    #[test]
+    fn propagate_trace_context() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        init_propagator();
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(new_tracer(&provider)));
+        tracing::subscriber::with_default(subscriber, || {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                "traceparent",
+                HeaderValue::from_static("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            );
+            let span = tracing::info_span!("request");
+            set_parent_from_headers(&span, &headers);
+            let mut resp_headers = HeaderMap::new();
+            inject_headers(&span, &mut resp_headers);
+            // 与上级使用相同的trace id，span id为新生成
+            let traceparent = resp_headers.get("traceparent").unwrap().to_str().unwrap();
+            assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            assert!(!traceparent.contains("00f067aa0ba902b7"));
+            assert!(!resp_headers.contains_key("tracestate"));
+        });
+    }
+}
@@ -0,0 +1,243 @@
+use axum::http::{header, HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use axum_server::Handle;
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime};
+use tracing::{error, info};
+
+use crate::config;
+
+// 证书与私钥文件(pem)，均设置时才启用https
+pub struct TlsConfig {
+    pub cert_file: String,
+    pub key_file: String,
+    // 检测证书是否更新的间隔，为0则不检测
+    pub reload_interval: Duration,
+    // http重定向至https的监听地址
+    pub redirect_addr: Option<SocketAddr>,
+    // 重定向的https端口
+    pub redirect_port: u16,
+}
+
+pub fn is_enabled() -> bool {
+    config::get_env("TLS_CERT_FILE").is_some() && config::get_env("TLS_KEY_FILE").is_some()
+}
+
+// 解析重定向的监听地址，失败则不启用重定向
+fn parse_redirect_addr(addr: &str) -> Option<SocketAddr> {
+    match addr.parse() {
+        Ok(addr) => Some(addr),
+        Err(err) => {
+            error!(category = "tls", addr, "parse redirect addr fail: {err}");
+            None
+        }
+    }
+}
+
+impl TlsConfig {
+    pub fn from_env() -> Option<Self> {
+        let cert_file = config::get_env("TLS_CERT_FILE")?;
+        let key_file = config::get_env("TLS_KEY_FILE")?;
+        Some(Self {
+            cert_file,
+            key_file,
+            reload_interval: Duration::from_secs(config::get_env_number(
+                "TLS_RELOAD_INTERVAL",
+                60_u64,
+            )),
+            redirect_addr: config::get_env("TLS_REDIRECT_ADDR")
+                .and_then(|addr| parse_redirect_addr(&addr)),
+            redirect_port: config::get_env_number("TLS_REDIRECT_PORT", 443_u16),
+        })
+    }
+    pub async fn new_rustls_config(&self) -> Result<RustlsConfig, String> {
+        RustlsConfig::from_pem_file(&self.cert_file, &self.key_file)
+            .await
+            .map_err(|err| err.to_string())
+    }
+    // 证书与私钥的修改时间
+    fn modified(&self) -> Option<(SystemTime, SystemTime)> {
+        let cert = fs::metadata(&self.cert_file).and_then(|value| value.modified());
+        let key = fs::metadata(&self.key_file).and_then(|value| value.modified());
+        Some((cert.ok()?, key.ok()?))
+    }
+}
+
+// 定时检测证书文件是否有修改，有修改则重新加载
+pub async fn watch_certificates(tls_config: TlsConfig, rustls_config: RustlsConfig) {
+    if tls_config.reload_interval.is_zero() {
+        return;
+    }
+    let mut modified = tls_config.modified();
+    let mut interval = tokio::time::interval(tls_config.reload_interval);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let current = tls_config.modified();
+        if current.is_none() || current == modified {
+            continue;
+        }
+        // 证书与私钥可能未同时更新完成，加载失败则下次再重试
+        match rustls_config
+            .reload_from_pem_file(&tls_config.cert_file, &tls_config.key_file)
+            .await
+        {
+            Ok(()) => {
+                modified = current;
+                info!(category = "tls", "certificate reloaded");
+            }
+            Err(err) => {
+                error!(category = "tls", "reload certificate fail: {err}");
+            }
+        }
+    }
+}
+
+// 将http请求重定向至https
+pub async fn redirect_to_https(port: u16, headers: HeaderMap, uri: Uri) -> Response {
+    let Some(host) = headers
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<axum::http::uri::Authority>().ok())
+    else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let host = if port == 443 {
+        host.host().to_string()
+    } else {
+        format!("{}:{port}", host.host())
+    };
+    let path = uri
+        .path_and_query()
+        .map(|value| value.as_str())
+        .unwrap_or("/");
+    (
+        StatusCode::PERMANENT_REDIRECT,
+        [(header::LOCATION, format!("https://{host}{path}"))],
+    )
+        .into_response()
+}
+
+// 启动http重定向服务，与https服务共用handle以同时关闭
+pub async fn serve_redirect(addr: SocketAddr, port: u16, handle: Handle) -> io::Result<()> {
+    let redirect = Router::new()
+        .fallback(move |headers: HeaderMap, uri: Uri| redirect_to_https(port, headers, uri));
+    info!("redirecting http://{addr}/ to https");
+    axum_server::bind(addr)
+        .handle(handle)
+        .serve(redirect.into_make_service())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    // 生成自签名证书，写入临时目录
+    fn write_self_signed(dir: &std::path::Path) {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        fs::write(dir.join("cert.pem"), cert.cert.pem()).unwrap();
+        fs::write(dir.join("key.pem"), cert.signing_key.serialize_pem()).unwrap();
+    }
+
+    fn new_tls_config(dir: &std::path::Path) -> TlsConfig {
+        TlsConfig {
+            cert_file: dir.join("cert.pem").to_string_lossy().to_string(),
+            key_file: dir.join("key.pem").to_string_lossy().to_string(),
+            reload_interval: Duration::from_millis(50),
+            redirect_addr: None,
+            redirect_port: 443,
+        }
+    }
+
+    fn new_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("location-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn load_and_reload_certificate() {
+        let dir = new_temp_dir("tls");
+        write_self_signed(&dir);
+        let tls_config = new_tls_config(&dir);
+        let rustls_config = tls_config.new_rustls_config().await.unwrap();
+        let original = rustls_config.get_inner();
+        tokio::spawn(watch_certificates(
+            new_tls_config(&dir),
+            rustls_config.clone(),
+        ));
+        // 等待watch记录首次的修改时间
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        write_self_signed(&dir);
+        // 避免文件系统的时间精度导致修改时间相同
+        let modified = SystemTime::now() + Duration::from_secs(5);
+        for file in ["cert.pem", "key.pem"] {
+            fs::File::options()
+                .write(true)
+                .open(dir.join(file))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+        let mut reloaded = false;
+        for _ in 0..100 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if !Arc::ptr_eq(&original, &rustls_config.get_inner()) {
+                reloaded = true;
+                break;
+            }
+        }
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(reloaded);
+    }
+
+    #[tokio::test]
+    async fn load_invalid_certificate() {
+        let dir = new_temp_dir("tls-invalid");
+        fs::write(dir.join("cert.pem"), "invalid").unwrap();
+        fs::write(dir.join("key.pem"), "invalid").unwrap();
+        let result = new_tls_config(&dir).new_rustls_config().await;
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_redirect_addrs() {
+        assert_eq!(
+            Some(SocketAddr::from(([0, 0, 0, 0], 80))),
+            parse_redirect_addr("0.0.0.0:80")
+        );
+        assert_eq!(None, parse_redirect_addr(":80"));
+    }
+
+    #[tokio::test]
+    async fn redirect_requests() {
+        let uri = Uri::from_static("/api/ip-locations/1.1.1.1?lang=en");
+        let mut headers = HeaderMap::new();
+        headers.insert(header::HOST, "example.com:80".parse().unwrap());
+
+        let resp = redirect_to_https(443, headers.clone(), uri.clone()).await;
+        assert_eq!(StatusCode::PERMANENT_REDIRECT, resp.status());
+        assert_eq!(
+            "https://example.com/api/ip-locations/1.1.1.1?lang=en",
+            resp.headers().get(header::LOCATION).unwrap()
+        );
+
+        let resp = redirect_to_https(8443, headers, uri.clone()).await;
+        assert_eq!(
+            "https://example.com:8443/api/ip-locations/1.1.1.1?lang=en",
+            resp.headers().get(header::LOCATION).unwrap()
+        );
+
+        let resp = redirect_to_https(443, HeaderMap::new(), uri).await;
+        assert_eq!(StatusCode::BAD_REQUEST, resp.status());
+    }
+}